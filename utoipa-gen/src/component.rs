@@ -19,7 +19,7 @@ use crate::{
 use crate::{schema_type::SchemaType, Deprecated};
 
 use self::features::attributes::{Description, Nullable};
-use self::features::validation::Minimum;
+use self::features::validation::{ItemMaximum, ItemMinimum, MaxLength, Minimum};
 use self::features::{
     pop_feature, Feature, FeaturesExt, IntoInner, IsInline, ToTokensExt, Validatable,
 };
@@ -443,7 +443,7 @@ impl TypeTree<'_> {
             "HashMap" | "Map" | "BTreeMap" => Some(GenericType::Map),
             #[cfg(feature = "indexmap")]
             "IndexMap" => Some(GenericType::Map),
-            "Vec" => Some(GenericType::Vec),
+            "Vec" | "VecDeque" => Some(GenericType::Vec),
             "BTreeSet" | "HashSet" => Some(GenericType::Set),
             "LinkedList" => Some(GenericType::LinkedList),
             #[cfg(feature = "smallvec")]
@@ -686,6 +686,14 @@ pub struct Container<'c> {
     pub generics: &'c Generics,
 }
 
+/// Warn about JSON examples defined for `format = Binary` schema.
+fn binary_example_warning(span: Span) -> TokenStream {
+    crate::deprecation_warning(
+        span,
+        "binary schema cannot be represented as JSON, consider removing examples from `format = Binary` field",
+    )
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct ComponentSchemaProps<'c> {
     pub container: &'c Container<'c>,
@@ -712,9 +720,9 @@ pub enum ComponentDescription<'c> {
     Description(&'c Description),
 }
 
-impl ToTokens for ComponentDescription<'_> {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let description = match self {
+impl ComponentDescription<'_> {
+    fn description_tokens(&self) -> TokenStream {
+        match self {
             Self::CommentAttributes(attributes) => {
                 if attributes.is_empty() {
                     TokenStream::new()
@@ -723,7 +731,13 @@ impl ToTokens for ComponentDescription<'_> {
                 }
             }
             Self::Description(description) => description.to_token_stream(),
-        };
+        }
+    }
+}
+
+impl ToTokens for ComponentDescription<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let description = self.description_tokens();
 
         if !description.is_empty() {
             tokens.extend(quote! {
@@ -733,6 +747,34 @@ impl ToTokens for ComponentDescription<'_> {
     }
 }
 
+/// Component description with the `note` of `#[deprecated(note = "...")]` attribute appended
+/// to it as a separate paragraph.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct DeprecatedDescription<'c> {
+    pub description: Option<ComponentDescription<'c>>,
+    pub note: String,
+}
+
+impl ToTokens for DeprecatedDescription<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let note = format!("Deprecated: {}", self.note);
+        let description = self
+            .description
+            .as_ref()
+            .map(ComponentDescription::description_tokens)
+            .filter(|description| !description.is_empty());
+
+        match description {
+            Some(description) => tokens.extend(quote! {
+                .description(Some(format!("{}\n\n{}", #description, #note)))
+            }),
+            None => tokens.extend(quote! {
+                .description(Some(#note))
+            }),
+        }
+    }
+}
+
 /// Used to store possible inner field schema name and tokens if field contains any schema
 /// references. E.g. field: Vec<Foo> should have name: Foo::name(), tokens: Foo::schema() and
 /// references: Foo::schemas()
@@ -744,9 +786,25 @@ pub struct SchemaReference {
     pub references: TokenStream,
     pub is_inline: bool,
     pub no_recursion: bool,
+    /// Rewritten path of the referenced type.
+    pub path: Option<syn::Path>,
 }
 
 impl SchemaReference {
+    /// Check whether `SchemaReference` points to the type `ident` itself either by `Self` or by
+    /// its own name regardless of generic arguments e.g. `Node<T>` within `Node<T>`. Collecting
+    /// such reference would recurse infinitely.
+    pub fn is_self_reference(&self, ident: &Ident) -> bool {
+        self.path.as_ref().is_some_and(|path| {
+            path.leading_colon.is_none()
+                && path.segments.len() == 1
+                && path
+                    .segments
+                    .first()
+                    .is_some_and(|segment| segment.ident == "Self" || segment.ident == *ident)
+        })
+    }
+
     /// Check whether `SchemaReference` is partial. Partial schema reference occurs in situation
     /// when reference schema tokens cannot be resolved e.g. type in question is generic argument.
     fn is_partial(&self) -> bool {
@@ -909,6 +967,22 @@ impl ComponentSchema {
         })
     }
 
+    /// Check whether given array like [`TypeTree`] holds bytes e.g. `Vec<u8>` or `&[u8]`.
+    fn is_byte_sequence(type_tree: &TypeTree) -> bool {
+        type_tree
+            .children
+            .as_ref()
+            .and_then(|children| children.first())
+            .and_then(|child| child.path.as_ref())
+            .is_some_and(|path| {
+                SchemaType {
+                    path: Cow::Borrowed(path),
+                    nullable: false,
+                }
+                .is_byte()
+            })
+    }
+
     /// Create `.schema_type(...)` override token stream if nullable is true from given [`SchemaTypeInner`].
     fn get_schema_type_override(
         nullable: Option<Nullable>,
@@ -933,6 +1007,23 @@ impl ComponentSchema {
         }
     }
 
+    fn integer_key_pattern(key: &TypeTree) -> Option<&'static str> {
+        if key.value_type != ValueType::Primitive {
+            return None;
+        }
+        let schema_type = SchemaType {
+            path: Cow::Borrowed(key.path.as_deref()?),
+            nullable: false,
+        };
+        if schema_type.is_unsigned_integer() {
+            Some("^[0-9]+$")
+        } else if schema_type.is_integer() {
+            Some("^-?[0-9]+$")
+        } else {
+            None
+        }
+    }
+
     fn map_to_tokens(
         tokens: &mut TokenStream,
         schema_references: &mut Vec<SchemaReference>,
@@ -949,32 +1040,50 @@ impl ComponentSchema {
         let default_tokens = as_tokens_or_diagnostics!(&default);
         let deprecated = pop_feature!(features => Feature::Deprecated(_)).try_to_token_stream()?;
 
+        let children = type_tree
+            .children
+            .as_ref()
+            .expect("ComponentSchema Map type should have children");
+        let key = children
+            .first()
+            .expect("ComponentSchema Map type shouldu have 2 child, getting first");
+        let key_alias = key.get_alias_type()?;
+        let key_alias = key_alias.as_ref().map_try(TypeTree::from_type)?;
+        let key = key_alias.as_ref().unwrap_or(key);
+
         let additional_properties = additional_properties
             .as_ref()
             .map_try(|feature| Ok(as_tokens_or_diagnostics!(feature)))?
             .or_else_try(|| {
-                let children = type_tree
-                    .children
-                    .as_ref()
-                    .expect("ComponentSchema Map type should have children");
                 // Get propertyNames
-                let property_name = children
-                    .first()
-                    .expect("ComponentSchema Map type shouldu have 2 child, getting first");
-                let property_name_alias = property_name.get_alias_type()?;
-                let property_name_alias =
-                    property_name_alias.as_ref().map_try(TypeTree::from_type)?;
-                let property_name_child = property_name_alias.as_ref().unwrap_or(property_name);
-
-                let mut property_name_features = features.clone();
-                property_name_features.push(Feature::Inline(true.into()));
-                let property_name_schema = ComponentSchema::new(ComponentSchemaProps {
-                    container,
-                    type_tree: property_name_child,
-                    features: property_name_features,
-                    description: None,
-                })?;
-                let property_name_tokens = property_name_schema.to_token_stream();
+                let property_name_child = key;
+                let property_name_tokens =
+                    if let Some(pattern) = Self::integer_key_pattern(property_name_child) {
+                        // integer keys are serialized as strings of digits in JSON
+                        Some(quote! {
+                            .property_names(Some(
+                                utoipa::openapi::ObjectBuilder::new()
+                                    .schema_type(utoipa::openapi::schema::Type::String)
+                                    .pattern(Some(#pattern))
+                            ))
+                        })
+                    } else if property_name_child.value_type == ValueType::Tuple
+                        && property_name_child.children.is_some()
+                    {
+                        // tuple keys have no JSON object key representation, serde_json fails to
+                        // serialize them thus the keys are left undefined
+                        None
+                    } else {
+                        let mut property_name_features = features.clone();
+                        property_name_features.push(Feature::Inline(true.into()));
+                        let property_name_schema = ComponentSchema::new(ComponentSchemaProps {
+                            container,
+                            type_tree: property_name_child,
+                            features: property_name_features,
+                            description: None,
+                        })?;
+                        Some(quote! { .property_names(Some(#property_name_schema)) })
+                    };
 
                 // Maps are treated as generic objects with no named properties and
                 // additionalProperties denoting the type
@@ -998,7 +1107,7 @@ impl ComponentSchema {
                 schema_references.extend(schema_property.schema_references);
 
                 Result::<Option<TokenStream>, Diagnostics>::Ok(Some(quote! {
-                    .property_names(Some(#property_name_tokens))
+                    #property_name_tokens
                     .additional_properties(Some(#schema_tokens))
                 }))
             })?;
@@ -1026,7 +1135,28 @@ impl ComponentSchema {
         type_tree: &TypeTree,
         description_stream: Option<&ComponentDescription<'_>>,
     ) -> Result<(), Diagnostics> {
+        // byte sequence with `format = Binary` is file content e.g. a multipart upload part
+        if ComponentSchema::is_byte_sequence(type_tree)
+            && features
+                .iter()
+                .any(|feature| matches!(feature, Feature::Format(format) if format.is_binary()))
+        {
+            let string: syn::Type = syn::parse_quote!(String);
+            let schema = ComponentSchema::new(ComponentSchemaProps {
+                container,
+                type_tree: &TypeTree::from_type(&string)?,
+                features,
+                description: description_stream,
+            })?;
+            schema.to_tokens(tokens);
+
+            return Ok(());
+        }
+
         let example = pop_feature!(features => Feature::Example(_));
+        let item_example = pop_feature!(features => Feature::ItemExample(_));
+        let item_maximum = pop_feature!(features => Feature::ItemMaximum(_) as Option<ItemMaximum>);
+        let item_minimum = pop_feature!(features => Feature::ItemMinimum(_) as Option<ItemMinimum>);
         let xml = features.extract_vec_xml_feature(type_tree)?;
         let max_items = pop_feature!(features => Feature::MaxItems(_));
         let min_items = pop_feature!(features => Feature::MinItems(_));
@@ -1062,6 +1192,9 @@ impl ComponentSchema {
         let alias = alias.as_ref().map_try(TypeTree::from_type)?;
         let child = alias.as_ref().unwrap_or(child);
 
+        features.extend(item_maximum.map(|ItemMaximum(maximum)| Feature::Maximum(maximum)));
+        features.extend(item_minimum.map(|ItemMinimum(minimum)| Feature::Minimum(minimum)));
+
         let component_schema = ComponentSchema::new(ComponentSchemaProps {
             container,
             type_tree: child,
@@ -1087,6 +1220,7 @@ impl ComponentSchema {
                 .items(#component_schema_tokens)
             #unique
         };
+        let item_example = as_tokens_or_diagnostics!(&item_example);
 
         let validate = |feature: &Feature| {
             let type_path = &**type_tree.path.as_ref().unwrap();
@@ -1101,6 +1235,7 @@ impl ComponentSchema {
 
         tokens.extend(quote! {
             #schema
+            #item_example
             #deprecated
             #description_stream
         });
@@ -1158,6 +1293,15 @@ impl ComponentSchema {
                         features.push(Minimum::new(0f64, type_path.span()).into());
                     }
                 }
+                if schema_type.is_char() {
+                    // char is always serialized as single character string
+                    if !features
+                        .iter()
+                        .any(|feature| matches!(&feature, Feature::MaxLength(_)))
+                    {
+                        features.push(MaxLength::new(1, type_path.span()).into());
+                    }
+                }
 
                 let schema_type_tokens = as_tokens_or_diagnostics!(&schema_type);
                 tokens.extend(quote! {
@@ -1177,6 +1321,30 @@ impl ComponentSchema {
                     feature.validate(&schema_type, type_tree);
                 }
                 let _ = pop_feature!(features => Feature::NoRecursion(_)); // primitive types are not recursive
+                if features
+                    .iter()
+                    .any(|feature| matches!(feature, Feature::Format(format) if format.is_binary()))
+                {
+                    if let Some(Feature::Example(example)) =
+                        pop_feature!(features => Feature::Example(_))
+                    {
+                        let warning = binary_example_warning(example.span());
+                        tokens.extend(quote! { .example(Some({ #warning #example })) });
+                    }
+                    if let Some(Feature::Examples(examples)) =
+                        pop_feature!(features => Feature::Examples(_))
+                    {
+                        let warning = binary_example_warning(examples.span());
+                        tokens.extend(quote! { .examples({ #warning #examples }) });
+                    }
+                }
+                if let Some(Feature::ConstValue(const_value)) =
+                    pop_feature!(features => Feature::ConstValue(_))
+                {
+                    // nullable constant must still accept `null` as a value
+                    let null = nullable.then(|| quote! { , utoipa::gen::serde_json::Value::Null });
+                    tokens.extend(quote! { .enum_values(Some([#const_value #null])) });
+                }
                 tokens.extend(features.to_token_stream()?);
             }
             ValueType::Value => {
@@ -1220,6 +1388,7 @@ impl ComponentSchema {
                         no_recursion: features
                             .iter()
                             .any(|feature| matches!(feature, Feature::NoRecursion(_))),
+                        path: Some(rewritten_path.clone()),
                         ..SchemaReference::default()
                     };
 
@@ -1241,6 +1410,14 @@ impl ComponentSchema {
                     let default_tokens = as_tokens_or_diagnostics!(&default);
                     let title = pop_feature!(features => Feature::Title(_));
                     let title_tokens = as_tokens_or_diagnostics!(&title);
+                    let access_features = [
+                        pop_feature!(features => Feature::WriteOnly(_)),
+                        pop_feature!(features => Feature::ReadOnly(_)),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>();
+                    let access_tokens = access_features.to_token_stream()?;
 
                     if is_inline {
                         let schema_type = SchemaType {
@@ -1290,6 +1467,8 @@ impl ComponentSchema {
                         let schema = if default.is_some()
                             || nullable
                             || title.is_some()
+                            || !deprecated.is_empty()
+                            || !access_features.is_empty()
                             || !description_tokens.is_empty()
                         {
                             quote_spanned! {type_path.span()=>
@@ -1298,6 +1477,8 @@ impl ComponentSchema {
                                     .item(#items_tokens)
                                 #title_tokens
                                 #default_tokens
+                                #deprecated
+                                #access_tokens
                                 #description_stream
                             }
                         } else {
@@ -1358,7 +1539,12 @@ impl ComponentSchema {
                         // TODO: refs support `summary` field but currently there is no such field
                         // on schemas more over there is no way to distinct the `summary` from
                         // `description` of the ref. Should we consider supporting the summary?
-                        let schema = if default.is_some() || nullable || title.is_some() {
+                        let schema = if default.is_some()
+                            || nullable
+                            || title.is_some()
+                            || !deprecated.is_empty()
+                            || !access_features.is_empty()
+                        {
                             composed_or_ref(quote_spanned! {type_path.span()=>
                                 utoipa::openapi::schema::OneOfBuilder::new()
                                     #nullable_item
@@ -1368,6 +1554,8 @@ impl ComponentSchema {
                                     )
                                     #title_tokens
                                     #default_tokens
+                                    #deprecated
+                                    #access_tokens
                             })
                         } else {
                             composed_or_ref(quote_spanned! {type_path.span()=>
@@ -1537,6 +1725,7 @@ impl ComponentSchema {
                     references: quote !{ <#rewritten_path as utoipa::ToSchema>::schemas(schemas) },
                     is_inline: false,
                     no_recursion: false,
+                    path: Some(rewritten_path),
                 }))
                 )
             } else {
@@ -1641,3 +1830,50 @@ impl ToTokensDiagnostics for FlattenedMapSchema {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use syn::DeriveInput;
+
+    use crate::{component::schema::Schema, ToTokensDiagnostics};
+
+    fn derive_schema(input: DeriveInput) -> String {
+        let schema = Schema::new(&input.data, &input.attrs, &input.ident, &input.generics).unwrap();
+        let mut tokens = proc_macro2::TokenStream::new();
+        schema.to_tokens(&mut tokens).unwrap();
+
+        tokens.to_string()
+    }
+
+    #[test]
+    fn binary_format_field_example_emits_deprecated_note() {
+        let tokens = derive_schema(syn::parse_quote! {
+            struct Upload {
+                #[schema(format = Binary, example = "file")]
+                file: String,
+            }
+        });
+
+        assert!(
+            tokens.contains(
+                "binary schema cannot be represented as JSON, consider removing examples from `format = Binary` field"
+            ),
+            "expected binary example note in: {tokens}"
+        );
+    }
+
+    #[test]
+    fn non_binary_format_field_example_emits_no_warning() {
+        let tokens = derive_schema(syn::parse_quote! {
+            struct Upload {
+                #[schema(format = Byte, example = "ZmlsZQ==")]
+                file: String,
+            }
+        });
+
+        assert!(
+            !tokens.contains("deprecated"),
+            "expected no binary example warning in: {tokens}"
+        );
+    }
+}
@@ -76,6 +76,7 @@ pub trait Parse {
 pub enum Feature {
     Example(attributes::Example),
     Examples(attributes::Examples),
+    ItemExample(attributes::ItemExample),
     Default(attributes::Default),
     Inline(attributes::Inline),
     XmlAttr(attributes::XmlAttr),
@@ -100,13 +101,19 @@ pub enum Feature {
     Required(attributes::Required),
     ContentEncoding(attributes::ContentEncoding),
     ContentMediaType(attributes::ContentMediaType),
+    ConstValue(attributes::ConstValue),
     Discriminator(attributes::Discriminator),
     Bound(attributes::Bound),
+    AllOf(attributes::AllOf),
     Ignore(attributes::Ignore),
     NoRecursion(attributes::NoRecursion),
+    Deref(attributes::Deref),
+    EnumVarnames(attributes::EnumVarnames),
     MultipleOf(validation::MultipleOf),
     Maximum(validation::Maximum),
     Minimum(validation::Minimum),
+    ItemMaximum(validation::ItemMaximum),
+    ItemMinimum(validation::ItemMinimum),
     ExclusiveMaximum(validation::ExclusiveMaximum),
     ExclusiveMinimum(validation::ExclusiveMinimum),
     MaxLength(validation::MaxLength),
@@ -173,6 +180,7 @@ impl ToTokensDiagnostics for Feature {
             Feature::Default(default) => quote! { .default(#default) },
             Feature::Example(example) => quote! { .example(Some(#example)) },
             Feature::Examples(examples) => quote! { .examples(#examples) },
+            Feature::ItemExample(item_example) => quote! { .item_example(Some(#item_example)) },
             Feature::XmlAttr(xml) => quote! { .xml(Some(#xml)) },
             Feature::Format(format) => quote! { .format(Some(#format)) },
             Feature::WriteOnly(write_only) => quote! { .write_only(Some(#write_only)) },
@@ -189,6 +197,11 @@ impl ToTokensDiagnostics for Feature {
             Feature::Explode(explode) => quote! { .explode(Some(#explode)) },
             Feature::Maximum(maximum) => quote! { .maximum(Some(#maximum)) },
             Feature::Minimum(minimum) => quote! { .minimum(Some(#minimum)) },
+            Feature::ItemMaximum(_) | Feature::ItemMinimum(_) => {
+                return Err(Diagnostics::new(
+                    "`item_maximum` and `item_minimum` are only supported on array types",
+                ))
+            }
             Feature::ExclusiveMaximum(exclusive_maximum) => {
                 quote! { .exclusive_maximum(Some(#exclusive_maximum)) }
             }
@@ -204,7 +217,7 @@ impl ToTokensDiagnostics for Feature {
                 quote! { .max_properties(Some(#max_properties)) }
             }
             Feature::MinProperties(min_properties) => {
-                quote! { .max_properties(Some(#min_properties)) }
+                quote! { .min_properties(Some(#min_properties)) }
             }
             Feature::SchemaWith(schema_with) => schema_with.to_token_stream(),
             Feature::Description(description) => quote! { .description(Some(#description)) },
@@ -214,11 +227,17 @@ impl ToTokensDiagnostics for Feature {
             }
             Feature::ContentEncoding(content_encoding) => quote! { .content_encoding(#content_encoding) },
             Feature::ContentMediaType(content_media_type) => quote! { .content_media_type(#content_media_type) },
+            Feature::ConstValue(_) => {
+                return Err(Diagnostics::new(
+                    "`const_value` is only supported on primitive types",
+                ))
+            }
             Feature::Discriminator(discriminator) => quote! { .discriminator(Some(#discriminator)) },
             Feature::Bound(_) => {
                 // specially handled on generating impl blocks.
                 TokenStream::new()
             }
+            Feature::AllOf(_) => return Err(Diagnostics::new("AllOf does not support `ToTokens`")),
             Feature::RenameAll(_) => {
                 return Err(Diagnostics::new("RenameAll feature does not support `ToTokens`"))
             }
@@ -231,6 +250,8 @@ impl ToTokensDiagnostics for Feature {
                 TokenStream::new()
             }
             Feature::NoRecursion(_) => return Err(Diagnostics::new("NoRecursion does not support `ToTokens`")),
+            Feature::Deref(_) => return Err(Diagnostics::new("Deref does not support `ToTokens`")),
+            Feature::EnumVarnames(_) => return Err(Diagnostics::new("EnumVarnames does not support `ToTokens`")),
             Feature::IntoParamsNames(_) => {
                 return Err(Diagnostics::new("Names feature does not support `ToTokens`")
                     .help("Names is only used with IntoParams to artificially give names for unnamed struct type `IntoParams`."))
@@ -267,6 +288,7 @@ impl Display for Feature {
             Feature::Default(default) => default.fmt(f),
             Feature::Example(example) => example.fmt(f),
             Feature::Examples(examples) => examples.fmt(f),
+            Feature::ItemExample(item_example) => item_example.fmt(f),
             Feature::XmlAttr(xml) => xml.fmt(f),
             Feature::Format(format) => format.fmt(f),
             Feature::WriteOnly(write_only) => write_only.fmt(f),
@@ -285,6 +307,8 @@ impl Display for Feature {
             Feature::MultipleOf(multiple_of) => multiple_of.fmt(f),
             Feature::Maximum(maximum) => maximum.fmt(f),
             Feature::Minimum(minimum) => minimum.fmt(f),
+            Feature::ItemMaximum(item_maximum) => item_maximum.fmt(f),
+            Feature::ItemMinimum(item_minimum) => item_minimum.fmt(f),
             Feature::ExclusiveMaximum(exclusive_maximum) => exclusive_maximum.fmt(f),
             Feature::ExclusiveMinimum(exclusive_minimum) => exclusive_minimum.fmt(f),
             Feature::MaxLength(max_length) => max_length.fmt(f),
@@ -302,10 +326,14 @@ impl Display for Feature {
             Feature::Required(required) => required.fmt(f),
             Feature::ContentEncoding(content_encoding) => content_encoding.fmt(f),
             Feature::ContentMediaType(content_media_type) => content_media_type.fmt(f),
+            Feature::ConstValue(const_value) => const_value.fmt(f),
             Feature::Discriminator(discriminator) => discriminator.fmt(f),
             Feature::Bound(bound) => bound.fmt(f),
+            Feature::AllOf(all_of) => all_of.fmt(f),
             Feature::Ignore(ignore) => ignore.fmt(f),
             Feature::NoRecursion(no_recursion) => no_recursion.fmt(f),
+            Feature::Deref(deref) => deref.fmt(f),
+            Feature::EnumVarnames(enum_varnames) => enum_varnames.fmt(f),
         }
     }
 }
@@ -316,6 +344,7 @@ impl Validatable for Feature {
             Feature::Default(default) => default.is_validatable(),
             Feature::Example(example) => example.is_validatable(),
             Feature::Examples(examples) => examples.is_validatable(),
+            Feature::ItemExample(item_example) => item_example.is_validatable(),
             Feature::XmlAttr(xml) => xml.is_validatable(),
             Feature::Format(format) => format.is_validatable(),
             Feature::WriteOnly(write_only) => write_only.is_validatable(),
@@ -334,6 +363,8 @@ impl Validatable for Feature {
             Feature::MultipleOf(multiple_of) => multiple_of.is_validatable(),
             Feature::Maximum(maximum) => maximum.is_validatable(),
             Feature::Minimum(minimum) => minimum.is_validatable(),
+            Feature::ItemMaximum(item_maximum) => item_maximum.is_validatable(),
+            Feature::ItemMinimum(item_minimum) => item_minimum.is_validatable(),
             Feature::ExclusiveMaximum(exclusive_maximum) => exclusive_maximum.is_validatable(),
             Feature::ExclusiveMinimum(exclusive_minimum) => exclusive_minimum.is_validatable(),
             Feature::MaxLength(max_length) => max_length.is_validatable(),
@@ -353,10 +384,14 @@ impl Validatable for Feature {
             Feature::Required(required) => required.is_validatable(),
             Feature::ContentEncoding(content_encoding) => content_encoding.is_validatable(),
             Feature::ContentMediaType(content_media_type) => content_media_type.is_validatable(),
+            Feature::ConstValue(const_value) => const_value.is_validatable(),
             Feature::Discriminator(discriminator) => discriminator.is_validatable(),
             Feature::Bound(bound) => bound.is_validatable(),
+            Feature::AllOf(all_of) => all_of.is_validatable(),
             Feature::Ignore(ignore) => ignore.is_validatable(),
             Feature::NoRecursion(no_recursion) => no_recursion.is_validatable(),
+            Feature::Deref(deref) => deref.is_validatable(),
+            Feature::EnumVarnames(enum_varnames) => enum_varnames.is_validatable(),
         }
     }
 }
@@ -379,6 +414,7 @@ is_validatable! {
     attributes::Default,
     attributes::Example,
     attributes::Examples,
+    attributes::ItemExample,
     attributes::XmlAttr,
     attributes::Format,
     attributes::WriteOnly,
@@ -402,13 +438,19 @@ is_validatable! {
     attributes::Required,
     attributes::ContentEncoding,
     attributes::ContentMediaType,
+    attributes::ConstValue,
     attributes::Discriminator,
     attributes::Bound,
+    attributes::AllOf,
     attributes::Ignore,
     attributes::NoRecursion,
+    attributes::Deref,
+    attributes::EnumVarnames,
     validation::MultipleOf = true,
     validation::Maximum = true,
     validation::Minimum = true,
+    validation::ItemMaximum,
+    validation::ItemMinimum,
     validation::ExclusiveMaximum = true,
     validation::ExclusiveMinimum = true,
     validation::MaxLength = true,
@@ -634,11 +676,14 @@ impl_feature_into_inner! {
     attributes::AdditionalProperties,
     attributes::Discriminator,
     attributes::Bound,
+    attributes::AllOf,
     attributes::Ignore,
     attributes::NoRecursion,
     validation::MultipleOf,
     validation::Maximum,
     validation::Minimum,
+    validation::ItemMaximum,
+    validation::ItemMinimum,
     validation::ExclusiveMaximum,
     validation::ExclusiveMinimum,
     validation::MaxLength,
@@ -1,6 +1,6 @@
 use std::mem;
 
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
 use syn::parse::ParseStream;
 use syn::punctuated::Punctuated;
@@ -66,6 +66,17 @@ impl Parse for Example {
     }
 }
 
+impl Example {
+    /// Get the example as literal if it is defined with a plain literal value.
+    pub fn as_lit(&self) -> Option<syn::Lit> {
+        self.0.as_lit()
+    }
+
+    pub fn span(&self) -> Span {
+        self.0.span()
+    }
+}
+
 impl ToTokens for Example {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         tokens.extend(self.0.to_token_stream())
@@ -103,6 +114,15 @@ impl Parse for Examples {
     }
 }
 
+impl Examples {
+    pub fn span(&self) -> Span {
+        self.0
+            .first()
+            .map(AnyValue::span)
+            .unwrap_or_else(Span::call_site)
+    }
+}
+
 impl ToTokens for Examples {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         if !self.0.is_empty() {
@@ -118,6 +138,30 @@ impl From<Examples> for Feature {
     }
 }
 
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct ItemExample(AnyValue);
+}
+
+impl Parse for ItemExample {
+    fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
+        parse_utils::parse_next(input, || AnyValue::parse_any(input)).map(Self)
+    }
+}
+
+impl ToTokens for ItemExample {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        tokens.extend(self.0.to_token_stream())
+    }
+}
+
+impl From<ItemExample> for Feature {
+    fn from(value: ItemExample) -> Self {
+        Feature::ItemExample(value)
+    }
+}
+
 impl_feature! {"xml" =>
     #[derive(Default, Clone)]
     #[cfg_attr(feature = "debug", derive(Debug))]
@@ -192,6 +236,12 @@ impl Parse for Format {
     }
 }
 
+impl Format {
+    pub fn is_binary(&self) -> bool {
+        matches!(self.0, KnownFormat::Binary)
+    }
+}
+
 impl ToTokens for Format {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         tokens.extend(self.0.to_token_stream())
@@ -246,6 +296,12 @@ impl ToTokens for ReadOnly {
     }
 }
 
+impl From<bool> for ReadOnly {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
 impl From<ReadOnly> for Feature {
     fn from(value: ReadOnly) -> Self {
         Feature::ReadOnly(value)
@@ -666,22 +722,14 @@ impl From<bool> for Deprecated {
 impl_feature! {
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[derive(Clone)]
-    pub struct As(pub TypePath);
+    pub struct As(String);
 }
 
 impl As {
-    /// Returns this `As` attribute type path formatted as string supported by OpenAPI spec whereas
-    /// double colons (::) are replaced with dot (.).
+    /// Returns the schema name defined by this `As` attribute. Type path is formatted as string
+    /// supported by OpenAPI spec whereas double colons (::) are replaced with dot (.).
     pub fn to_schema_formatted_string(&self) -> String {
-        // See: https://github.com/juhaku/utoipa/pull/187#issuecomment-1173101405
-        // :: are not officially supported in the spec
-        self.0
-            .path
-            .segments
-            .iter()
-            .map(|segment| segment.ident.to_string())
-            .collect::<Vec<_>>()
-            .join(".")
+        self.0.clone()
     }
 }
 
@@ -690,7 +738,24 @@ impl Parse for As {
     where
         Self: std::marker::Sized,
     {
-        parse_utils::parse_next(input, || input.parse()).map(Self)
+        parse_utils::parse_next(input, || {
+            if input.peek(LitStr) {
+                input.parse::<LitStr>().map(|name| name.value())
+            } else {
+                // See: https://github.com/juhaku/utoipa/pull/187#issuecomment-1173101405
+                // :: are not officially supported in the spec
+                input.parse::<TypePath>().map(|type_path| {
+                    type_path
+                        .path
+                        .segments
+                        .iter()
+                        .map(|segment| segment.ident.to_string())
+                        .collect::<Vec<_>>()
+                        .join(".")
+                })
+            }
+        })
+        .map(Self)
     }
 }
 
@@ -835,6 +900,30 @@ impl From<ContentMediaType> for Feature {
     }
 }
 
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct ConstValue(AnyValue);
+}
+
+impl Parse for ConstValue {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self> {
+        parse_utils::parse_next(input, || AnyValue::parse_any(input)).map(Self)
+    }
+}
+
+impl ToTokens for ConstValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl From<ConstValue> for Feature {
+    fn from(value: ConstValue) -> Self {
+        Self::ConstValue(value)
+    }
+}
+
 // discriminator = ...
 // discriminator(property_name = ..., mapping(
 //      (value = ...),
@@ -989,6 +1078,32 @@ impl From<Bound> for Feature {
     }
 }
 
+// all_of(Base, other::Base)
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct AllOf(pub(crate) Vec<syn::Type>);
+}
+
+impl Parse for AllOf {
+    fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
+        let types;
+        syn::parenthesized!(types in input);
+
+        Ok(Self(
+            Punctuated::<syn::Type, Token![,]>::parse_terminated(&types)?
+                .into_iter()
+                .collect(),
+        ))
+    }
+}
+
+impl From<AllOf> for Feature {
+    fn from(value: AllOf) -> Self {
+        Feature::AllOf(value)
+    }
+}
+
 impl_feature! {
     /// Ignore feature parsed from macro attributes.
     #[derive(Clone)]
@@ -1045,3 +1160,49 @@ impl From<NoRecursion> for Feature {
         Self::NoRecursion(value)
     }
 }
+
+// Nothing to parse, it is considered to be set when attribute itself is parsed via
+// `parse_features!`.
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Deref;
+}
+
+impl Parse for Deref {
+    fn parse(_: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        Ok(Self)
+    }
+}
+
+impl From<Deref> for Feature {
+    fn from(value: Deref) -> Self {
+        Self::Deref(value)
+    }
+}
+
+// Nothing to parse, it is considered to be set when attribute itself is parsed via
+// `parse_features!`.
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct EnumVarnames;
+}
+
+impl Parse for EnumVarnames {
+    fn parse(_: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        Ok(Self)
+    }
+}
+
+impl From<EnumVarnames> for Feature {
+    fn from(value: EnumVarnames) -> Self {
+        Self::EnumVarnames(value)
+    }
+}
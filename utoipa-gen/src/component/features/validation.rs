@@ -204,6 +204,48 @@ impl From<Minimum> for Feature {
     }
 }
 
+impl_feature! {
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Clone)]
+    pub struct ItemMaximum(pub(crate) Maximum);
+}
+
+impl Parse for ItemMaximum {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: Sized,
+    {
+        parse_next_number_value(input).map(|number| Self(Maximum(number, ident)))
+    }
+}
+
+impl From<ItemMaximum> for Feature {
+    fn from(value: ItemMaximum) -> Self {
+        Feature::ItemMaximum(value)
+    }
+}
+
+impl_feature! {
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Clone)]
+    pub struct ItemMinimum(pub(crate) Minimum);
+}
+
+impl Parse for ItemMinimum {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: Sized,
+    {
+        parse_next_number_value(input).map(|number| Self(Minimum(number, ident)))
+    }
+}
+
+impl From<ItemMinimum> for Feature {
+    fn from(value: ItemMinimum) -> Self {
+        Feature::ItemMinimum(value)
+    }
+}
+
 impl_feature! {
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[derive(Clone)]
@@ -284,6 +326,18 @@ impl_feature! {
     pub struct MaxLength(pub(super) NumberValue, Ident);
 }
 
+impl MaxLength {
+    pub fn new(value: usize, span: Span) -> Self {
+        Self(
+            NumberValue {
+                minus: false,
+                lit: Literal::usize_unsuffixed(value),
+            },
+            Ident::new("empty", span),
+        )
+    }
+}
+
 impl Validate for MaxLength {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
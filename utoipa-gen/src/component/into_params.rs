@@ -13,8 +13,8 @@ use crate::{
         features::{
             self,
             attributes::{
-                AdditionalProperties, AllowReserved, Example, Explode, Format, Ignore, Inline,
-                IntoParamsNames, Nullable, ReadOnly, Rename, RenameAll, SchemaWith, Style,
+                AdditionalProperties, AllowReserved, Deprecated, Example, Explode, Format, Ignore,
+                Inline, IntoParamsNames, Nullable, ReadOnly, Rename, RenameAll, SchemaWith, Style,
                 WriteOnly, XmlAttr,
             },
             validation::{
@@ -26,7 +26,7 @@ use crate::{
     },
     doc_comment::CommentAttributes,
     parse_utils::LitBoolOrExprPath,
-    Array, Diagnostics, OptionExt, Required, ToTokensDiagnostics,
+    Array, AttributesExt, Diagnostics, OptionExt, Required, ToTokensDiagnostics,
 };
 
 use super::{
@@ -123,7 +123,7 @@ impl ToTokensDiagnostics for IntoParams {
             .collect::<Result<Vec<_>, Diagnostics>>()?
             .into_iter()
             .filter_map(|(index, field, field_serde_params, field_features)| {
-                if field_serde_params.skip {
+                if field_serde_params.skip || field_serde_params.skip_deserializing {
                     None
                 } else {
                     Some((index, field, field_serde_params, field_features))
@@ -280,6 +280,7 @@ impl Parse for FieldFeatures {
             AllowReserved,
             Example,
             Explode,
+            Deprecated,
             SchemaWith,
             component::features::attributes::Required,
             // param schema features
@@ -368,7 +369,10 @@ impl Param {
             },
         );
 
-        if let Some(deprecated) = super::get_deprecated(&field.attrs) {
+        if let Some(deprecated) = pop_feature!(param_features => Feature::Deprecated(_)) {
+            let deprecated = crate::as_tokens_or_diagnostics!(&deprecated);
+            tokens.extend(deprecated);
+        } else if let Some(deprecated) = super::get_deprecated(&field.attrs) {
             tokens.extend(quote! { .deprecated(Some(#deprecated)) });
         }
 
@@ -377,8 +381,9 @@ impl Param {
             let schema_with = crate::as_tokens_or_diagnostics!(&schema_with);
             tokens.extend(quote! { .schema(Some(#schema_with)).build() });
         } else {
-            let description =
-                CommentAttributes::from_attributes(&field.attrs).as_formatted_string();
+            let description = CommentAttributes::from_attributes(&field.attrs)
+                .with_deprecated_note(field.attrs.deprecated_note())
+                .as_formatted_string();
             if !description.is_empty() {
                 tokens.extend(quote! { .description(Some(#description))})
             }
@@ -4,7 +4,7 @@ use proc_macro2::{Ident, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use syn::{
     parse_quote, punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, Data, Field,
-    Fields, FieldsNamed, FieldsUnnamed, Generics, Variant,
+    Fields, FieldsNamed, FieldsUnnamed, Generics, Variant, WherePredicate,
 };
 
 use crate::{
@@ -28,12 +28,12 @@ use self::{
 
 use super::{
     features::{
-        attributes::{self, As, Bound, Description, NoRecursion, RenameAll},
+        attributes::{self, AllOf, As, Bound, Description, NoRecursion, Nullable, RenameAll},
         parse_features, pop_feature, Feature, FeaturesExt, IntoInner, ToTokensExt,
     },
     serde::{self, SerdeContainer, SerdeValue},
-    ComponentDescription, ComponentSchema, FieldRename, FlattenedMapSchema, SchemaReference,
-    TypeTree, VariantRename,
+    ComponentDescription, ComponentSchema, DeprecatedDescription, FieldRename, FlattenedMapSchema,
+    SchemaReference, TypeTree, VariantRename,
 };
 
 mod enums;
@@ -84,7 +84,9 @@ impl ToTokensDiagnostics for Schema<'_> {
         let variant = SchemaVariant::new(self.data, &root)?;
         let (generic_references, schema_references): (Vec<_>, Vec<_>) = variant
             .get_schema_references()
-            .filter(|schema_reference| !schema_reference.no_recursion)
+            .filter(|schema_reference| {
+                !schema_reference.no_recursion && !schema_reference.is_self_reference(ident)
+            })
             .partition(|schema_reference| schema_reference.is_partial());
 
         struct SchemaRef<'a>(&'a TokenStream, &'a TokenStream, &'a TokenStream, bool);
@@ -157,6 +159,14 @@ impl ToTokensDiagnostics for Schema<'_> {
                     .predicates
                     .push(parse_quote!(#param : utoipa::ToSchema))
             }
+            // mirror serde bounds since the same constraints are needed by field types
+            if let Some(bound) = serde::parse_container(self.attributes)?.bound {
+                let predicates = syn::parse::Parser::parse_str(
+                    Punctuated::<WherePredicate, Comma>::parse_terminated,
+                    &bound,
+                )?;
+                where_clause.predicates.extend(predicates);
+            }
         }
 
         tokens.extend(quote! {
@@ -190,6 +200,7 @@ enum SchemaVariant<'a> {
     Unnamed(UnnamedStructSchema),
     Enum(EnumSchema<'a>),
     Unit(UnitStructVariant),
+    Deref(DerefStructSchema),
 }
 
 impl<'a> SchemaVariant<'a> {
@@ -204,6 +215,10 @@ impl<'a> SchemaVariant<'a> {
                         .into_inner()
                         .unwrap_or_default();
 
+                    if DerefStructSchema::is_deref(&unnamed_features) {
+                        return Ok(Self::Deref(DerefStructSchema::new(unnamed_features)?));
+                    }
+
                     Ok(Self::Unnamed(UnnamedStructSchema::new(
                         root,
                         unnamed,
@@ -218,6 +233,10 @@ impl<'a> SchemaVariant<'a> {
                         .into_inner()
                         .unwrap_or_default();
 
+                    if DerefStructSchema::is_deref(&named_features) {
+                        return Ok(Self::Deref(DerefStructSchema::new(named_features)?));
+                    }
+
                     Ok(Self::Named(NamedStructSchema::new(
                         root,
                         named,
@@ -239,6 +258,7 @@ impl<'a> SchemaVariant<'a> {
             Self::Enum(schema) => &schema.schema_as,
             Self::Named(schema) => &schema.schema_as,
             Self::Unnamed(schema) => &schema.schema_as,
+            Self::Deref(schema) => &schema.schema_as,
             _ => &None,
         }
     }
@@ -248,6 +268,7 @@ impl<'a> SchemaVariant<'a> {
             Self::Named(schema) => schema.fields_references.iter(),
             Self::Unnamed(schema) => schema.schema_references.iter(),
             Self::Enum(schema) => schema.schema_references.iter(),
+            Self::Deref(schema) => schema.schema_references.iter(),
             _ => [].iter(),
         }
     }
@@ -257,6 +278,7 @@ impl<'a> SchemaVariant<'a> {
             SchemaVariant::Named(schema) => schema.bound.as_ref(),
             SchemaVariant::Unnamed(schema) => schema.bound.as_ref(),
             SchemaVariant::Enum(schema) => schema.bound.as_ref(),
+            SchemaVariant::Deref(schema) => schema.bound.as_ref(),
             SchemaVariant::Unit(_) => None,
         }
     }
@@ -269,6 +291,7 @@ impl ToTokens for SchemaVariant<'_> {
             Self::Named(schema) => schema.to_tokens(tokens),
             Self::Unnamed(schema) => schema.to_tokens(tokens),
             Self::Unit(unit) => unit.to_tokens(tokens),
+            Self::Deref(schema) => schema.to_tokens(tokens),
         }
     }
 }
@@ -310,6 +333,59 @@ impl ToTokens for UnitStructVariant {
     }
 }
 
+/// Schema of a struct marked with `#[schema(deref)]`. The struct does not have a schema of its
+/// own but it uses the schema of its [`std::ops::Deref::Target`] type instead.
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct DerefStructSchema {
+    schema_as: Option<As>,
+    schema_references: Vec<SchemaReference>,
+    bound: Option<Bound>,
+}
+
+impl DerefStructSchema {
+    fn is_deref(features: &[Feature]) -> bool {
+        features
+            .iter()
+            .any(|feature| matches!(feature, Feature::Deref(_)))
+    }
+
+    fn new(mut features: Vec<Feature>) -> Result<Self, Diagnostics> {
+        let _ = pop_feature!(features => Feature::Deref(_));
+        let schema_as = pop_feature!(features => Feature::As(_) as Option<As>);
+        let bound = pop_feature!(features => Feature::Bound(_) as Option<Bound>);
+
+        if let Some(feature) = features.first() {
+            return Err(Diagnostics::new(format!(
+                "`{feature}` attribute cannot be used together with `deref`"
+            ))
+            .help("Only `as` and `bound` attributes are allowed with `deref`"));
+        }
+
+        // Target schema is resolved only at runtime thus it is considered partial reference
+        // and only its dependencies are collected.
+        let schema_references = vec![SchemaReference {
+            references: quote! {
+                <<Self as std::ops::Deref>::Target as utoipa::ToSchema>::schemas(schemas)
+            },
+            ..Default::default()
+        }];
+
+        Ok(Self {
+            schema_as,
+            schema_references,
+            bound,
+        })
+    }
+}
+
+impl ToTokens for DerefStructSchema {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(quote! {
+            <<Self as std::ops::Deref>::Target as utoipa::PartialSchema>::schema()
+        })
+    }
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct NamedStructSchema {
     tokens: TokenStream,
@@ -341,6 +417,7 @@ impl NamedStructSchema {
         let description: Option<Description> =
             pop_feature!(features => Feature::Description(_)).into_inner();
         let bound = pop_feature!(features => Feature::Bound(_) as Option<Bound>);
+        let all_of_bases = pop_feature!(features => Feature::AllOf(_) as Option<AllOf>);
 
         let container_rules = serde::parse_container(root.attributes)?;
 
@@ -376,7 +453,7 @@ impl NamedStructSchema {
             })
             .collect::<Result<Vec<_>, Diagnostics>>()?;
 
-        let fields_references = fields_vec
+        let mut fields_references = fields_vec
             .iter_mut()
             .filter_map(|(field_options, field_rules, ..)| {
                 match (&mut field_options.property, field_rules.skip) {
@@ -477,8 +554,25 @@ impl NamedStructSchema {
             .filter(|(_, field_rules, ..)| field_rules.flatten)
             .collect::<Vec<_>>();
 
-        let all_of = if !flatten_fields.is_empty() {
-            let mut flattened_tokens = TokenStream::new();
+        let mut flattened_tokens = TokenStream::new();
+        if let Some(AllOf(bases)) = all_of_bases {
+            let container = super::Container {
+                generics: root.generics,
+            };
+            for base in &bases {
+                let type_tree = TypeTree::from_type(base)?;
+                let mut base_schema = ComponentSchema::new(super::ComponentSchemaProps {
+                    container: &container,
+                    type_tree: &type_tree,
+                    features: Vec::new(),
+                    description: None,
+                })?;
+                fields_references.append(&mut base_schema.schema_references);
+                flattened_tokens.extend(quote! { .item(#base_schema) });
+            }
+        }
+
+        let all_of = if !flatten_fields.is_empty() || !flattened_tokens.is_empty() {
             let mut flattened_map_field = None;
 
             for (options, _, _, field) in flatten_fields {
@@ -556,7 +650,10 @@ impl NamedStructSchema {
             .map(ComponentDescription::Description)
             .or(Some(ComponentDescription::CommentAttributes(&comments)));
 
-        description.to_tokens(&mut tokens);
+        match root.attributes.deprecated_note() {
+            Some(note) => DeprecatedDescription { description, note }.to_tokens(&mut tokens),
+            None => description.to_tokens(&mut tokens),
+        }
 
         Ok(Self {
             tokens,
@@ -616,6 +713,15 @@ impl NamedStructSchema {
             field_features.push(Feature::Deprecated(true.into()));
         }
 
+        // field that is never deserialized can only be read from the api
+        if field_rules.skip_deserializing
+            && !field_features
+                .iter()
+                .any(|feature| matches!(feature, Feature::ReadOnly(_)))
+        {
+            field_features.push(Feature::ReadOnly(true.into()));
+        }
+
         let rename_field =
             pop_feature!(field_features => Feature::Rename(_)).and_then(|feature| match feature {
                 Feature::Rename(rename) => Some(Cow::Owned(rename.into_value())),
@@ -626,18 +732,31 @@ impl NamedStructSchema {
         let override_type_tree = value_type
             .as_ref()
             .map_try(|value_type| value_type.as_type_tree())?;
-        let comments = CommentAttributes::from_attributes(&field.attrs);
+        let comments = CommentAttributes::from_attributes(&field.attrs)
+            .with_deprecated_note(field.attrs.deprecated_note());
         let description = &ComponentDescription::CommentAttributes(&comments);
 
         let schema_with = pop_feature!(field_features => Feature::SchemaWith(_));
         let required = pop_feature!(field_features => Feature::Required(_) as Option<crate::component::features::attributes::Required>);
+        let is_option_field = type_tree.is_option();
+        // keep nullability of `Option` field when its type is overridden with non `Option` type
+        if is_option_field
+            && override_type_tree
+                .as_ref()
+                .is_some_and(|override_type_tree| !override_type_tree.is_option())
+            && !field_features
+                .iter()
+                .any(|feature| matches!(feature, Feature::Nullable(_)))
+        {
+            field_features.push(Nullable::new().into());
+        }
         let type_tree = override_type_tree.as_ref().unwrap_or(type_tree);
 
         let alias_type = type_tree.get_alias_type()?;
         let alias_type_tree = alias_type.as_ref().map_try(TypeTree::from_type)?;
         let type_tree = alias_type_tree.as_ref().unwrap_or(type_tree);
 
-        let is_option = type_tree.is_option();
+        let is_option = is_option_field || type_tree.is_option();
 
         let ignore = match pop_feature!(field_features => Feature::Ignore(_)) {
             Some(Feature::Ignore(attributes::Ignore(bool_or_exp))) => Some(bool_or_exp),
@@ -858,7 +977,8 @@ impl<'e> EnumSchema<'e> {
                             super::features::attributes::Default,
                             super::features::attributes::Title,
                             crate::component::features::attributes::Deprecated,
-                            As
+                            As,
+                            crate::component::features::attributes::EnumVarnames
                         ))
                     })?
                     .unwrap_or_default()
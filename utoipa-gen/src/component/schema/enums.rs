@@ -36,6 +36,7 @@ enum PlainEnumRepr<'p> {
 pub struct PlainEnum<'e> {
     pub root: &'e Root<'e>,
     enum_variant: PlainEnumRepr<'e>,
+    varnames: Option<Array<'e, String>>,
     serde_enum_repr: SerdeEnumRepr,
     features: Vec<Feature>,
     pub description: Option<Description>,
@@ -55,6 +56,7 @@ impl<'e> PlainEnum<'e> {
 
         let rename_all = pop_feature!(features => Feature::RenameAll(_) as Option<RenameAll>);
         let description = pop_feature!(features => Feature::Description(_) as Option<Description>);
+        let enum_varnames = pop_feature!(features => Feature::EnumVarnames(_)).is_some();
 
         let container_rules = serde::parse_container(root.attributes)?;
         let variants_iter = variants
@@ -66,25 +68,41 @@ impl<'e> PlainEnum<'e> {
             .collect::<Result<Vec<_>, Diagnostics>>()?
             .into_iter()
             .filter_map(|(variant, variant_rules)| {
-                if variant_rules.skip {
+                if variant_rules.skip || variant_rules.skip_deserializing {
                     None
                 } else {
                     Some((variant, variant_rules))
                 }
             });
 
+        let mut varnames = None;
         let enum_variant = match repr_type_path {
-            Some(repr_type_path) => PlainEnumRepr::Repr(
-                variants_iter
-                    .map(|(variant, _)| {
-                        let ty = &variant.ident;
-                        quote! {
-                            Self::#ty as #repr_type_path
-                        }
-                    })
-                    .collect::<Array<TokenStream>>(),
-                repr_type_path,
-            ),
+            Some(repr_type_path) => {
+                let variants = variants_iter
+                    .map(|(variant, _)| variant)
+                    .collect::<Vec<_>>();
+                if enum_varnames {
+                    varnames = Some(
+                        variants
+                            .iter()
+                            .map(|variant| variant.ident.to_string())
+                            .collect::<Array<String>>(),
+                    );
+                }
+
+                PlainEnumRepr::Repr(
+                    variants
+                        .iter()
+                        .map(|variant| {
+                            let ty = &variant.ident;
+                            quote! {
+                                Self::#ty as #repr_type_path
+                            }
+                        })
+                        .collect::<Array<TokenStream>>(),
+                    repr_type_path,
+                )
+            }
             None => PlainEnumRepr::Plain(
                 variants_iter
                     .map(|(variant, variant_rules)| {
@@ -121,6 +139,7 @@ impl<'e> PlainEnum<'e> {
         Ok(Self {
             root,
             enum_variant,
+            varnames,
             features,
             serde_enum_repr: container_rules.enum_repr,
             description,
@@ -167,6 +186,16 @@ impl ToTokens for PlainEnum<'_> {
             SerdeEnumRepr::ExternallyTagged => {
                 EnumSchema::<PlainSchema>::with_types(variants, schema_type, enum_type)
                     .to_tokens(tokens);
+
+                if let Some(varnames) = &self.varnames {
+                    tokens.extend(quote! {
+                        .extensions(Some(
+                            utoipa::openapi::extensions::ExtensionsBuilder::new()
+                                .add("x-enum-varnames", utoipa::gen::serde_json::json!(#varnames))
+                                .build()
+                        ))
+                    })
+                }
             }
             SerdeEnumRepr::InternallyTagged { tag } => {
                 let items = variants
@@ -265,7 +294,7 @@ impl<'p> MixedEnum<'p> {
             .collect::<Result<Vec<_>, Diagnostics>>()?
             .into_iter()
             .filter_map(|(variant, variant_rules)| {
-                if variant_rules.skip {
+                if variant_rules.skip || variant_rules.skip_deserializing {
                     None
                 } else {
                     let variant_features = match &variant.fields {
@@ -589,9 +618,17 @@ impl MixedEnumContent {
         );
         let name = renamed.unwrap_or(Cow::Owned(name));
 
+        // variant deprecation is already part of the variant features, it must not leak to the
+        // schema of the unnamed field
+        let attributes = variant
+            .attrs
+            .iter()
+            .filter(|attribute| !attribute.path().is_ident("deprecated"))
+            .cloned()
+            .collect::<Vec<_>>();
         let root = &Root {
             ident: &variant.ident,
-            attributes: &variant.attrs,
+            attributes: &attributes,
             generics: root.generics,
         };
 
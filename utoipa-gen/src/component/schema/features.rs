@@ -6,15 +6,16 @@ use syn::{
 use crate::{
     component::features::{
         attributes::{
-            AdditionalProperties, As, Bound, ContentEncoding, ContentMediaType, Deprecated,
-            Description, Discriminator, Example, Examples, Format, Ignore, Inline, NoRecursion,
-            Nullable, ReadOnly, Rename, RenameAll, Required, SchemaWith, Title, ValueType,
-            WriteOnly, XmlAttr,
+            AdditionalProperties, AllOf, As, Bound, ConstValue, ContentEncoding, ContentMediaType,
+            Deprecated, Deref, Description, Discriminator, Example, Examples, Format, Ignore,
+            Inline, ItemExample, NoRecursion, Nullable, ReadOnly, Rename, RenameAll, Required,
+            SchemaWith, Title, ValueType, WriteOnly, XmlAttr,
         },
         impl_into_inner, impl_merge, parse_features,
         validation::{
-            ExclusiveMaximum, ExclusiveMinimum, MaxItems, MaxLength, MaxProperties, Maximum,
-            MinItems, MinLength, MinProperties, Minimum, MultipleOf, Pattern,
+            ExclusiveMaximum, ExclusiveMinimum, ItemMaximum, ItemMinimum, MaxItems, MaxLength,
+            MaxProperties, Maximum, MinItems, MinLength, MinProperties, Minimum, MultipleOf,
+            Pattern,
         },
         Feature, Merge,
     },
@@ -39,7 +40,9 @@ impl Parse for NamedFieldStructFeatures {
             Deprecated,
             Description,
             Bound,
-            NoRecursion
+            NoRecursion,
+            Deref,
+            AllOf
         )))
     }
 }
@@ -63,9 +66,11 @@ impl Parse for UnnamedFieldStructFeatures {
             Description,
             ContentEncoding,
             ContentMediaType,
+            ConstValue,
             Bound,
             NoRecursion,
-            Pattern
+            Pattern,
+            Deref
         )))
     }
 }
@@ -120,6 +125,8 @@ impl Parse for NamedFieldFeatures {
         Ok(NamedFieldFeatures(parse_features!(
             input as Example,
             Examples,
+            ItemExample,
+            Title,
             ValueType,
             Format,
             crate::component::features::attributes::Default,
@@ -132,6 +139,8 @@ impl Parse for NamedFieldFeatures {
             MultipleOf,
             Maximum,
             Minimum,
+            ItemMaximum,
+            ItemMinimum,
             ExclusiveMaximum,
             ExclusiveMinimum,
             MaxLength,
@@ -145,6 +154,7 @@ impl Parse for NamedFieldFeatures {
             Deprecated,
             ContentEncoding,
             ContentMediaType,
+            ConstValue,
             Ignore,
             NoRecursion
         )))
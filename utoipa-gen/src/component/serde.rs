@@ -26,6 +26,7 @@ fn parse_next_lit_str(next: Cursor) -> Option<(String, Span)> {
 #[cfg_attr(test, derive(PartialEq, Eq))]
 pub struct SerdeValue {
     pub skip: bool,
+    pub skip_deserializing: bool,
     pub rename: Option<String>,
     pub default: bool,
     pub flatten: bool,
@@ -45,13 +46,12 @@ impl SerdeValue {
             let mut rest = *cursor;
             while let Some((tt, next)) = rest.token_tree() {
                 match tt {
-                    TokenTree::Ident(ident)
-                        if ident == "skip"
-                            || ident == "skip_serializing"
-                            || ident == "skip_deserializing" =>
-                    {
+                    TokenTree::Ident(ident) if ident == "skip" || ident == "skip_serializing" => {
                         value.skip = true
                     }
+                    TokenTree::Ident(ident) if ident == "skip_deserializing" => {
+                        value.skip_deserializing = true
+                    }
                     TokenTree::Ident(ident) if ident == "skip_serializing_if" => {
                         value.skip_serializing_if = true
                     }
@@ -118,6 +118,7 @@ pub struct SerdeContainer {
     pub enum_repr: SerdeEnumRepr,
     pub default: bool,
     pub deny_unknown_fields: bool,
+    pub bound: Option<String>,
 }
 
 impl SerdeContainer {
@@ -128,6 +129,7 @@ impl SerdeContainer {
     ///     * `untagged = ...`
     ///     * `default = ...`
     ///     * `deny_unknown_fields`
+    ///     * `bound = ...`
     fn parse_attribute(&mut self, ident: Ident, next: Cursor) -> syn::Result<()> {
         match ident.to_string().as_str() {
             "rename_all" => {
@@ -192,6 +194,11 @@ impl SerdeContainer {
             "deny_unknown_fields" => {
                 self.deny_unknown_fields = true;
             }
+            "bound" => {
+                if let Some((literal, _)) = parse_next_lit_str(next) {
+                    self.bound = Some(literal);
+                }
+            }
             _ => {}
         }
         Ok(())
@@ -232,6 +239,9 @@ pub fn parse_value(attributes: &[Attribute]) -> Result<SerdeValue, Diagnostics>
             if value.skip {
                 acc.skip = value.skip;
             }
+            if value.skip_deserializing {
+                acc.skip_deserializing = value.skip_deserializing;
+            }
             if value.skip_serializing_if {
                 acc.skip_serializing_if = value.skip_serializing_if;
             }
@@ -282,6 +292,9 @@ pub fn parse_container(attributes: &[Attribute]) -> Result<SerdeContainer, Diagn
             if value.rename_all.is_some() {
                 acc.rename_all = value.rename_all;
             }
+            if value.bound.is_some() {
+                acc.bound = value.bound;
+            }
 
             acc
         }))
@@ -491,16 +504,21 @@ mod tests {
         let unsupported_attribute: syn::Attribute = parse_quote! {
             #[serde(expecting = "...")]
         };
+        let bound_attribute: syn::Attribute = parse_quote! {
+            #[serde(bound = "T: Clone")]
+        };
         let attributes: &[Attribute] = &[
             default_attribute_1,
             default_attribute_2,
             deny_unknown_fields_attribute,
             unsupported_attribute,
+            bound_attribute,
         ];
 
         let expected = SerdeContainer {
             default: true,
             deny_unknown_fields: true,
+            bound: Some("T: Clone".to_string()),
             ..Default::default()
         };
 
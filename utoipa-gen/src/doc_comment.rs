@@ -50,6 +50,17 @@ impl CommentAttributes {
         Self(docs)
     }
 
+    /// Append the `note` of `#[deprecated(note = "...")]` as a separate paragraph.
+    pub(crate) fn with_deprecated_note(mut self, note: Option<String>) -> Self {
+        if let Some(note) = note {
+            if !self.0.is_empty() {
+                self.0.push(String::new());
+            }
+            self.0.push(format!("Deprecated: {note}"));
+        }
+        self
+    }
+
     pub(crate) fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
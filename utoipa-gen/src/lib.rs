@@ -81,15 +81,19 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 /// You can use the Rust's own `#[deprecated]` attribute on any struct, enum or field to mark it as deprecated and it will
 /// reflect to the generated OpenAPI spec.
 ///
-/// `#[deprecated]` attribute supports adding additional details such as a reason and or since version but this is is not supported in
-/// OpenAPI. OpenAPI has only a boolean flag to determine deprecation. While it is totally okay to declare deprecated with reason
-/// `#[deprecated  = "There is better way to do this"]` the reason would not render in OpenAPI spec.
+/// `#[deprecated]` attribute supports adding additional details such as a reason and or since version but OpenAPI
+/// has only a boolean flag to determine deprecation. When a struct or a named field is declared deprecated
+/// with a reason e.g. `#[deprecated(note = "There is better way to do this")]` or
+/// `#[deprecated = "There is better way to do this"]` the reason will be appended to the schema or property
+/// description as `Deprecated: There is better way to do this`. Reasons on enums will not render in OpenAPI spec.
 ///
 /// Doc comments on fields will resolve to field descriptions in generated OpenAPI doc. On struct
 /// level doc comments will resolve to object descriptions.
 ///
 /// Schemas derived with `ToSchema` will be automatically collected from usage. In case of looping
 /// schema tree _`no_recursion`_ attribute must be used to break from recurring into infinite loop.
+/// Fields referencing the type itself e.g. `children: Vec<Node>` within `Node` are referenced
+/// with `$ref` and need no _`no_recursion`_ attribute.
 /// See [more details from example][derive@ToSchema#examples]. All arguments of generic schemas
 /// must implement `ToSchema` trait.
 ///
@@ -120,9 +124,10 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   __serde__ will take precedence.
 /// * `as = ...` Can be used to define alternative path and name for the schema what will be used in
 ///   the OpenAPI. E.g _`as = path::to::Pet`_. This would make the schema appear in the generated
-///   OpenAPI spec as _`path.to.Pet`_. This same name will be used throughout the OpenAPI generated
-///   with `utoipa` when the type is being referenced in [`OpenApi`][openapi_derive] derive macro
-///   or in [`utoipa::path(...)`][path_macro] macro.
+///   OpenAPI spec as _`path.to.Pet`_. The name can also be given as string literal e.g.
+///   _`as = "pets.Pet"`_ in which case it is used as is. This same name will be used throughout
+///   the OpenAPI generated with `utoipa` when the type is being referenced in
+///   [`OpenApi`][openapi_derive] derive macro or in [`utoipa::path(...)`][path_macro] macro.
 /// * `bound = ...` Can be used to override default trait bounds on generated `impl`s.
 ///   See [Generic schemas section](#generic-schemas) below for more details.
 /// * `default` Can be used to populate default values on all fields using the struct's
@@ -138,6 +143,13 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///  `Owner` -> `Pet`. _`no_recursion`_ attribute must be used within `Ower` type not to allow
 ///  recurring into `Pet`. Failing to do so will cause infinite loop and runtime **panic**. On
 ///  struct level the _`no_recursion`_ rule will be applied to all of its fields.
+/// * `deref` Can be used to make the struct transparent and use schema of its
+///   [`Deref::Target`][std::ops::Deref::Target] instead of the schema derived from the fields.
+///   The struct must implement [`Deref`][std::ops::Deref] and the target type must implement
+///   [`ToSchema`][to_schema]. Only `as` and `bound` attributes can be used together with `deref`.
+/// * `all_of(...)` Comma separated list of base types implementing [`ToSchema`][to_schema] to
+///   compose with the struct. E.g. _`all_of(Base)`_ will generate an _`allOf`_ schema with a
+///   reference to `Base` followed by the struct's own properties as an inline object.
 ///
 /// ## Named Fields Optional Configuration Options for `#[schema(...)]`
 ///
@@ -145,6 +157,11 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   **Deprecated since OpenAPI 3.0, using `examples` is preferred instead.**
 /// * `examples(..., ...)` Comma separated list defining multiple _`examples`_ for the schema. Each
 ///   _`example`_ Can be any value e.g. literal, method reference or _`json!(...)`_.
+/// * `item_example = ...` Can be any value e.g. literal, method reference or _`json!(...)`_.
+///   Defines example for the items of an array field such as _`Vec<T>`_. Referenced item types
+///   are wrapped in _`allOf`_ in order to hold the example.
+/// * `title = ...` Literal string value. Can be used to define title for the field in OpenAPI
+///   document. Titles of referenced types are placed on a wrapping _`oneOf`_ schema.
 /// * `default = ...` Can be any value e.g. literal, method reference or _`json!(...)`_.
 /// * `format = ...` May either be variant of the [`KnownFormat`][known_format] enum, or otherwise
 ///   an open value as a string. By default the format is derived from the type of the property
@@ -160,8 +177,11 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   or _`Value`_.
 ///   _`Object`_ will be rendered as generic OpenAPI object _(`type: object`)_.
 ///   _`Value`_ will be rendered as any OpenAPI value (i.e. no `type` restriction).
+///   If the field is _`Option<T>`_ the overridden type will be nullable as well.
 /// * `inline` If the type of this field implements [`ToSchema`][to_schema], then the schema definition
 ///   will be inlined. **warning:** Don't use this for recursive data types!
+///   For collections the inlining applies to the items, e.g. `#[schema(inline)] statuses: Vec<Status>`
+///   renders the `Status` enum values directly in the array `items` instead of a `$ref`.
 ///   
 ///   **Note!**<br>Using `inline` with generic arguments might lead to incorrect spec generation.
 ///   This is due to the fact that during compilation we cannot know how to treat the generic
@@ -176,6 +196,10 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   division will result an `integer`. Value must be strictly above _`0`_.
 /// * `maximum = ...` Can be used to define inclusive upper bound to a `number` value.
 /// * `minimum = ...` Can be used to define inclusive lower bound to a `number` value.
+/// * `item_maximum = ...` Can be used to define inclusive upper bound to the `number` items of an
+///   array field such as _`Vec<i32>`_.
+/// * `item_minimum = ...` Can be used to define inclusive lower bound to the `number` items of an
+///   array field such as _`Vec<i32>`_.
 /// * `exclusive_maximum = ...` Can be used to define exclusive upper bound to a `number` value.
 /// * `exclusive_minimum = ...` Can be used to define exclusive lower bound to a `number` value.
 /// * `max_length = ...` Can be used to define maximum length for `string` types.
@@ -199,6 +223,9 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   See [`Object::content_encoding`][schema_object_encoding]
 /// * `content_media_type = ...` Can be used to define MIME type of a string for underlying schema object.
 ///   See [`Object::content_media_type`][schema_object_media_type]
+/// * `const_value = ...` Can be used to restrict primitive field to a single value e.g. `const_value = true`
+///   for boolean flag. The value is rendered as one element `enum`. Nullable fields also allow `null`.
+///   Accepts any value e.g. literal, method reference or _`json!(...)`_.
 ///* `ignore` or `ignore = ...` Can be used to skip the field from being serialized to OpenAPI schema. It accepts either a literal `bool` value
 ///   or a path to a function that returns `bool` (`Fn() -> bool`).
 ///* `no_recursion` Is used to break from recursion in case of looping schema tree e.g. `Pet` ->
@@ -254,9 +281,10 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   struct.
 /// * `as = ...` Can be used to define alternative path and name for the schema what will be used in
 ///   the OpenAPI. E.g _`as = path::to::Pet`_. This would make the schema appear in the generated
-///   OpenAPI spec as _`path.to.Pet`_. This same name will be used throughout the OpenAPI generated
-///   with `utoipa` when the type is being referenced in [`OpenApi`][openapi_derive] derive macro
-///   or in [`utoipa::path(...)`][path_macro] macro.
+///   OpenAPI spec as _`path.to.Pet`_. The name can also be given as string literal e.g.
+///   _`as = "pets.Pet"`_ in which case it is used as is. This same name will be used throughout
+///   the OpenAPI generated with `utoipa` when the type is being referenced in
+///   [`OpenApi`][openapi_derive] derive macro or in [`utoipa::path(...)`][path_macro] macro.
 /// * `bound = ...` Can be used to override default trait bounds on generated `impl`s.
 ///   See [Generic schemas section](#generic-schemas) below for more details.
 /// * `deprecated` Can be used to mark the field as deprecated in the generated OpenAPI spec but
@@ -266,9 +294,15 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   See [`Object::content_encoding`][schema_object_encoding]
 /// * `content_media_type = ...` Can be used to define MIME type of a string for underlying schema object.
 ///   See [`Object::content_media_type`][schema_object_media_type]
+/// * `const_value = ...` Can be used to restrict primitive type to a single value e.g. `const_value = true`.
+///   The value is rendered as one element `enum`.
 ///* `no_recursion` Is used to break from recursion in case of looping schema tree e.g. `Pet` ->
 ///  `Owner` -> `Pet`. _`no_recursion`_ attribute must be used within `Ower` type not to allow
 ///  recurring into `Pet`. Failing to do so will cause infinite loop and runtime **panic**.
+/// * `deref` Can be used to make the struct transparent and use schema of its
+///   [`Deref::Target`][std::ops::Deref::Target] instead of the schema derived from the fields.
+///   The struct must implement [`Deref`][std::ops::Deref] and the target type must implement
+///   [`ToSchema`][to_schema]. Only `as` and `bound` attributes can be used together with `deref`.
 ///
 /// # Enum Optional Configuration Options for `#[schema(...)]`
 ///
@@ -290,9 +324,10 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   are defined __serde__ will take precedence.
 /// * `as = ...` Can be used to define alternative path and name for the schema what will be used in
 ///   the OpenAPI. E.g _`as = path::to::Pet`_. This would make the schema appear in the generated
-///   OpenAPI spec as _`path.to.Pet`_. This same name will be used throughout the OpenAPI generated
-///   with `utoipa` when the type is being referenced in [`OpenApi`][openapi_derive] derive macro
-///   or in [`utoipa::path(...)`][path_macro] macro.
+///   OpenAPI spec as _`path.to.Pet`_. The name can also be given as string literal e.g.
+///   _`as = "pets.Pet"`_ in which case it is used as is. This same name will be used throughout
+///   the OpenAPI generated with `utoipa` when the type is being referenced in
+///   [`OpenApi`][openapi_derive] derive macro or in [`utoipa::path(...)`][path_macro] macro.
 /// * `bound = ...` Can be used to override default trait bounds on generated `impl`s.
 ///   See [Generic schemas section](#generic-schemas) below for more details.
 /// * `deprecated` Can be used to mark the enum as deprecated in the generated OpenAPI spec but
@@ -323,9 +358,10 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   are defined __serde__ will take precedence.
 /// * `as = ...` Can be used to define alternative path and name for the schema what will be used in
 ///   the OpenAPI. E.g _`as = path::to::Pet`_. This would make the schema appear in the generated
-///   OpenAPI spec as _`path.to.Pet`_. This same name will be used throughout the OpenAPI generated
-///   with `utoipa` when the type is being referenced in [`OpenApi`][openapi_derive] derive macro
-///   or in [`utoipa::path(...)`][path_macro] macro.
+///   OpenAPI spec as _`path.to.Pet`_. The name can also be given as string literal e.g.
+///   _`as = "pets.Pet"`_ in which case it is used as is. This same name will be used throughout
+///   the OpenAPI generated with `utoipa` when the type is being referenced in
+///   [`OpenApi`][openapi_derive] derive macro or in [`utoipa::path(...)`][path_macro] macro.
 /// * `bound = ...` Can be used to override default trait bounds on generated `impl`s.
 ///   See [Generic schemas section](#generic-schemas) below for more details.
 /// * `deprecated` Can be used to mark the enum as deprecated in the generated OpenAPI spec but
@@ -479,7 +515,8 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 /// * `rename = "..."` Supported **only** at the field or variant level.
 /// * `skip = "..."` Supported  **only** at the field or variant level.
 /// * `skip_serializing = "..."` Supported  **only** at the field or variant level.
-/// * `skip_deserializing = "..."` Supported  **only** at the field or variant level.
+/// * `skip_deserializing = "..."` Supported  **only** at the field or variant level. Fields are rendered
+///   as `readOnly` since they are still serialized, variants are skipped.
 /// * `skip_serializing_if = "..."` Supported  **only** at the field level.
 /// * `with = ...` Supported **only at field level.**
 /// * `tag = "..."` Supported at the container level.
@@ -490,6 +527,9 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///    enum representation](https://serde.rs/enum-representations.html#untagged).
 /// * `default` Supported at the container level and field level according to [serde attributes].
 /// * `deny_unknown_fields` Supported at the container level.
+/// * `bound = "..."` Supported at the container level. The bounds are added to the default
+///   [`ToSchema`][to_schema] bounds of generated `impl`s unless overridden with _`schema`_
+///   _`bound`_.
 /// * `flatten` Supported at the field level.
 ///
 /// Other _`serde`_ attributes works as is but does not have any effect on the generated OpenAPI doc.
@@ -571,9 +611,13 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   struct.
 /// * `as = ...` Can be used to define alternative path and name for the schema what will be used in
 ///   the OpenAPI. E.g _`as = path::to::Pet`_. This would make the schema appear in the generated
-///   OpenAPI spec as _`path.to.Pet`_. This same name will be used throughout the OpenAPI generated
-///   with `utoipa` when the type is being referenced in [`OpenApi`][openapi_derive] derive macro
-///   or in [`utoipa::path(...)`][path_macro] macro.
+///   OpenAPI spec as _`path.to.Pet`_. The name can also be given as string literal e.g.
+///   _`as = "pets.Pet"`_ in which case it is used as is. This same name will be used throughout
+///   the OpenAPI generated with `utoipa` when the type is being referenced in
+///   [`OpenApi`][openapi_derive] derive macro or in [`utoipa::path(...)`][path_macro] macro.
+/// * `enum_varnames` Opt-in to add _`x-enum-varnames`_ extension listing the Rust variant names
+///   in the same order as the numeric _`enum`_ values. Code generators use it to name the values.
+///   Only applies to enums that are not tagged with serde.
 ///
 /// _**Create enum with numeric values.**_
 /// ```rust
@@ -605,12 +649,26 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///  }
 /// ```
 ///
+/// Explicit discriminants alone do not make enum numeric as serde serializes such enums by the
+/// variant names. Enum is rendered with the discriminant values only with `#[repr(...)]` attribute.
+///
+/// _**Enum without `repr` is rendered as `enum: ["Low", "High"]`.**_
+/// ```rust
+/// # use utoipa::ToSchema;
+/// #[derive(ToSchema)]
+/// enum Level {
+///     Low = 1,
+///     High = 2,
+/// }
+/// ```
+///
 /// # Generic schemas
 ///
 /// Utoipa supports full set of deeply nested generics as shown below. The type will implement
 /// [`ToSchema`][to_schema] if and only if all the generic types implement `ToSchema` by default.
 /// That is in Rust `impl<T> ToSchema for MyType<T> where T: Schema { ... }`.
-/// You can also specify `bound = ...` on the item to override the default auto bounds.
+/// You can also specify `bound = ...` on the item to override the default auto bounds. Bounds
+/// defined with _`#[serde(bound = "...")]`_ are added to the default bounds.
 ///
 /// The _`as = ...`_ attribute is used to define the prefixed or alternative name for the component
 /// in question. This same name will be used throughout the OpenAPI generated with `utoipa` when
@@ -652,6 +710,27 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 /// When generic types are registered to the `OpenApi` the full type declaration must be provided.
 /// See the full example in test [schema_generics.rs](https://github.com/juhaku/utoipa/blob/master/utoipa-gen/tests/schema_generics.rs)
 ///
+/// # Map keys
+///
+/// [`HashMap`](std::collections::HashMap) and [`BTreeMap`](std::collections::BTreeMap) fields are
+/// rendered as `object` with _`additionalProperties`_ describing the values and _`propertyNames`_
+/// describing the keys. Integer keys are serialized by serde as strings thus _`propertyNames`_ of
+/// e.g. `BTreeMap<u32, String>` is a `string` with pattern `^[0-9]+$`.
+///
+/// Tuple keys have no JSON object key representation and serde_json fails to serialize them.
+/// Maps with such keys are rendered as a generic `object` with only _`additionalProperties`_
+/// describing the values. E.g. `BTreeMap<(u32, u32), String>` is an `object` of `string` values.
+///
+/// ```rust
+/// # use std::collections::BTreeMap;
+/// # use utoipa::ToSchema;
+///  #[derive(ToSchema)]
+///  struct Index {
+///      names: BTreeMap<u32, String>,
+///      ranges: BTreeMap<(u32, u32), String>,
+///  }
+/// ```
+///
 /// # Examples
 ///
 /// _**Simple example of a Pet with descriptions and object level example.**_
@@ -699,6 +778,28 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 /// }
 /// ```
 ///
+/// _**Compute complex examples in a function instead of inlining large _`json!(...)`_.**_
+///
+/// The method is called at schema generation time and may be given either as a plain reference
+/// e.g. `Pet::sample` or with call syntax e.g. `Pet::sample()`. The expected signature is
+/// `fn() -> serde_json::Value` but any `fn() -> T` where `T: Serialize` is accepted.
+/// ```rust
+/// # use utoipa::ToSchema;
+/// # use serde_json::{json, Value};
+/// #[derive(ToSchema)]
+/// #[schema(example = Pet::sample())]
+/// struct Pet {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// impl Pet {
+///     fn sample() -> Value {
+///         json!({ "id": 1, "name": "bob the cat" })
+///     }
+/// }
+/// ```
+///
 /// _**For enums and unnamed field structs you can define `schema` at type level.**_
 /// ```rust
 /// # use utoipa::ToSchema;
@@ -779,6 +880,45 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 /// }
 /// ```
 ///
+/// _**Byte sequences such as `Vec<u8>` with `format = Binary` are rendered as binary [`String`]
+/// without need for `value_type`.**_
+/// ```rust
+/// # use utoipa::ToSchema;
+/// #[derive(ToSchema)]
+/// struct Upload {
+///     name: String,
+///     #[schema(format = Binary, content_media_type = "application/octet-stream")]
+///     file: Vec<u8>,
+/// }
+/// ```
+///
+/// _**Custom format which closely resembles a [`KnownFormat`][known_format] emits a deprecation
+/// warning suggesting the known format. Custom formats are still allowed, the warning can be
+/// silenced with `#[allow(deprecated)]`.**_
+/// ```compile_fail
+/// # #![deny(deprecated)]
+/// # use utoipa::ToSchema;
+/// #[derive(ToSchema)]
+/// struct Post {
+///     // warning: custom format `datetime` is not a known format, did you mean `format = DateTime` (`date-time`)?
+///     #[schema(format = "datetime")]
+///     created: String,
+/// }
+/// ```
+///
+/// _**Examples defined for a `format = Binary` field emit a deprecation warning since binary
+/// content cannot be represented as JSON example.**_
+/// ```compile_fail
+/// # #![deny(deprecated)]
+/// # use utoipa::ToSchema;
+/// #[derive(ToSchema)]
+/// struct Upload {
+///     // warning: binary schema cannot be represented as JSON, consider removing examples from `format = Binary` field
+///     #[schema(value_type = String, format = Binary, example = "file content")]
+///     file: Vec<u8>,
+/// }
+/// ```
+///
 /// _**Enforce type being used in OpenAPI spec to [`String`] with `value_type` option.**_
 /// ```rust
 /// # use utoipa::ToSchema;
@@ -978,9 +1118,10 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// You can use the Rust's own `#[deprecated]` attribute on functions to mark it as deprecated and it will
 /// reflect to the generated OpenAPI spec. Only **parameters** has a special **deprecated** attribute to define them as deprecated.
 ///
-/// `#[deprecated]` attribute supports adding additional details such as a reason and or since version but this is is not supported in
-/// OpenAPI. OpenAPI has only a boolean flag to determine deprecation. While it is totally okay to declare deprecated with reason
-/// `#[deprecated  = "There is better way to do this"]` the reason would not render in OpenAPI spec.
+/// `#[deprecated]` attribute supports adding additional details such as a reason and or since version but OpenAPI
+/// has only a boolean flag to determine deprecation. When a function is declared deprecated with a reason e.g.
+/// `#[deprecated(note = "There is better way to do this")]` or `#[deprecated = "There is better way to do this"]`
+/// the reason will be appended to the operation description as `Deprecated: There is better way to do this`.
 ///
 /// Doc comment at decorated function will be used for _`description`_ and _`summary`_ of the path.
 /// First line of the doc comment will be used as the _`summary`_ while the remaining lines will be
@@ -1003,6 +1144,23 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///   provided.**_
 ///
 /// * `path = "..."` Must be OpenAPI format compatible str with arguments within curly braces. E.g _`{id}`_
+///   Parameters declared with _`Path`_ location in _`params(...)`_ are checked against the
+///   arguments of the path and the _`context_path`_ when both are literal strings. When path
+///   parameters are declared, every argument must have one unless some parameters are resolved
+///   from [`IntoParams`][into_params] types or handler arguments with framework extras. Otherwise
+///   it fails to compile. Declaring a path parameter without matching argument emits a
+///   deprecation warning instead, since the argument might come from a _`nest(...)`_ path prefix.
+///   ```compile_fail
+///   # #![deny(deprecated)]
+///   #[utoipa::path(
+///       get,
+///       path = "/pets/{id}",
+///       // warning: path parameter `pet_id` does not match any placeholder in path `/pets/{id}`
+///       params(("pet_id" = u64, Path, description = "Pet id")),
+///       responses((status = 200))
+///   )]
+///   fn get_pet(pet_id: u64) {}
+///   ```
 ///
 /// * `impl_for = ...` Optional type to implement the [`Path`][path] trait. By default a new type
 ///   is used for the implementation.
@@ -1026,6 +1184,16 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// * `request_body = ... | request_body(...)` Defining request body indicates that the request is expecting request body within
 ///   the performed request.
 ///
+/// * `request_body_from_arg` Infer request body from the last handler argument that is not declared
+///   in `params(...)` when `request_body` is not defined. This is best-effort: references are
+///   dereferenced and `Json<T>` is unwrapped to `T`. E.g. `fn create_pet(id: i32, pet: Json<Pet>)`
+///   with `params(("id" = i32, Path))` will use `Pet` as request body.
+///
+/// * `mirror_request_content_type` Use content type of the request body as content type of the
+///   responses that do not define _`content_type`_ themselves. E.g. request body with
+///   `content_type = "application/x-www-form-urlencoded"` makes `(status = 200, body = Pet)`
+///   response form typed as well. Nothing is mirrored if request body has multiple content types.
+///
 /// * `responses(...)` Slice of responses the endpoint is going to possibly return to the caller.
 ///
 /// * `params(...)` Slice of params that the endpoint accepts.
@@ -1038,6 +1206,9 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// * `description = ...` Allows overriding description of the path. Value can be literal string or valid
 ///   rust expression e.g. `include_str!(...)` or `const` reference.
 ///
+/// * `external_docs(url = "...", description = "...")` Link the path operation to external
+///   documentation. `url` is required and `description` is optional.
+///
 /// # Request Body Attributes
 ///
 /// ## Simple format definition by `request_body = ...`
@@ -1072,7 +1243,9 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///   which is the default for [`ToSchema`][to_schema] types. _`ref("./external.json")`_
 ///   can be used to reference external json file for body schema. **Note!** Utoipa does **not** guarantee
 ///   that free form _`ref`_ is accessible via OpenAPI doc or Swagger UI, users are responsible for making
-///   these guarantees.
+///   these guarantees. Anonymous object can be defined in place with
+///   _`content = object(name = Type, ...)`_, see _`body`_ in [Response Attributes](#response-attributes)
+///   for more details.
 ///
 /// * `content_type = "..."` Can be used to override the default behavior
 ///   of auto resolving the content type from the `content` attribute. If defined the value should be valid
@@ -1146,6 +1319,25 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///   that free form _`ref`_ is accessible via OpenAPI doc or Swagger UI, users are responsible for making
 ///   these guarantees.
 ///
+///   Small ad-hoc object not worth a named component can be defined in place with
+///   _`body = object(name = Type, ...)`_. Each property is _`name = Type`_ or _`name = inline(Type)`_
+///   where the name can also be a string literal e.g. _`"access-token" = String`_. Properties are
+///   required unless the type is an `Option`. E.g.
+///   _`body = object(token = String, expires_in = Option<u64>)`_ is rendered as an inline `object`
+///   with required `token` and optional `expires_in` properties.
+///
+///   The given _`Type`_ must implement [`ToSchema`][to_schema] unless it is a primitive or a known
+///   generic type. Using a type without it fails to compile with an unsatisfied trait bound error
+///   pointing to the type in the _`body`_ attribute.
+///   ```compile_fail
+///   struct Pet {
+///       name: String,
+///   }
+///
+///   #[utoipa::path(get, path = "/pet", responses((status = 200, body = Pet)))]
+///   fn get_pet() {}
+///   ```
+///
 /// * `content_type = "..."` Can be used to override the default behavior
 ///   of auto resolving the content type from the `body` attribute. If defined the value should be valid
 ///   content type such as _`application/json`_ . By default the content type is _`text/plain`_
@@ -1155,11 +1347,19 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// * `headers(...)` Slice of response headers that are returned back to a caller.
 ///
 /// * `example = ...` Can be _`json!(...)`_. _`json!(...)`_ should be something that
-///   _`serde_json::json!`_ can parse as a _`serde_json::Value`_.
+///   _`serde_json::json!`_ can parse as a _`serde_json::Value`_. The example is placed on the
+///   media type object of the response. To place the example on the schema instead use
+///   _`#[schema(example = ...)]`_ on the body type.
 ///
 /// * `response = ...` Type what implements [`ToResponse`][to_response_trait] trait. This can alternatively be used to
 ///    define response attributes. _`response`_ attribute cannot co-exist with other than _`status`_ attribute.
 ///
+/// * `ref_response = ...` Name of a response defined in _`components.responses`_ e.g.
+///   _`ref_response = "InternalError"`_. Value can be literal string or expression. This will render
+///   _`$ref: #/components/responses/InternalError`_. The response itself must be registered
+///   separately e.g. with [`Modify`][modify] or [`ComponentsBuilder::response`][components_response].
+///   _`ref_response`_ attribute cannot co-exist with other than _`status`_ attribute.
+///
 /// * `content((...), (...))` Can be used to define multiple return types for single response status. Supports same syntax as
 ///   [multiple request body content][`macro@path#multiple-request-body-content`].
 ///
@@ -1334,7 +1534,8 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// * `in` _**Must be placed after name or parameter_type**_. Define the place of the parameter.
 ///   This must be one of the variants of [`openapi::path::ParameterIn`][in_enum].
-///   E.g. _`Path, Query, Header, Cookie`_
+///   E.g. _`Path, Query, Header, Cookie`_. Lowercase names _`path, query, header, cookie`_ matching
+///   the serialized _`in`_ values are accepted as well.
 ///
 /// * `deprecated` Define whether the parameter is deprecated or not. Can optionally be defined
 ///    with explicit `bool` value as _`deprecated = bool`_.
@@ -1348,7 +1549,18 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// * `allow_reserved` Defines whether reserved characters _`:/?#[]@!$&'()*+,;=`_ is allowed within value.
 ///
 /// * `example = ...` Can method reference or _`json!(...)`_. Given example
-///   will override any example in underlying parameter type.
+///   will override any example in underlying parameter type. When the example is a plain literal
+///   and _`parameter_type`_ is a primitive type, the literal must match the type, otherwise a
+///   compile error is emitted.
+///   ```compile_fail
+///   #[utoipa::path(
+///       get,
+///       path = "/pets/{id}",
+///       params(("id" = i64, Path, example = "one")),
+///       responses((status = 200))
+///   )]
+///   fn get_pet(id: i64) {}
+///   ```
 ///
 /// ##### Parameter type attributes
 ///
@@ -1616,12 +1828,12 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// async fn test_images(_body: Vec<u8>) {}
 /// ```
 ///
-/// _**Example of sending `multipart` form.**_
+/// _**Example of sending `multipart` form with a binary file part.**_
 /// ```rust
 /// #[derive(utoipa::ToSchema)]
 /// struct MyForm {
 ///     order_id: i32,
-///     #[schema(content_media_type = "application/octet-stream")]
+///     #[schema(format = Binary, content_media_type = "application/octet-stream")]
 ///     file_bytes: Vec<u8>,
 /// }
 ///
@@ -1873,16 +2085,10 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// [server_derive_syntax]: derive.OpenApi.html#servers-attribute-syntax
 /// [server]: openapi/server/struct.Server.html
 /// [file_uploads]: <https://spec.openapis.org/oas/v3.1.0.html#considerations-for-file-uploads>
+/// [modify]: trait.Modify.html
+/// [components_response]: openapi/schema/struct.ComponentsBuilder.html#method.response
 pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let path_attribute = syn::parse_macro_input!(attr as PathAttr);
-
-    #[cfg(any(
-        feature = "actix_extras",
-        feature = "rocket_extras",
-        feature = "axum_extras",
-        feature = "auto_into_responses"
-    ))]
-    let mut path_attribute = path_attribute;
+    let mut path_attribute = syn::parse_macro_input!(attr as PathAttr);
 
     let ast_fn = match syn::parse::<ItemFn>(item) {
         Ok(ast_fn) => ast_fn,
@@ -1944,11 +2150,17 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
         path_attribute.update_request_body(body);
     }
 
+    path_attribute.update_request_body_from_args(&ast_fn.sig.inputs);
+    if let Err(diagnostics) = path_attribute.update_responses_content_type() {
+        return diagnostics.into_token_stream().into();
+    }
+
     let path = Path::new(path_attribute, &ast_fn.sig.ident)
         .ext_methods(resolved_methods.map(|operation| operation.methods))
         .path(resolved_path.map(|path| path.path))
         .doc_comments(CommentAttributes::from_attributes(&ast_fn.attrs).0)
-        .deprecated(ast_fn.attrs.has_deprecated());
+        .deprecated(ast_fn.attrs.has_deprecated())
+        .deprecated_note(ast_fn.attrs.deprecated_note());
 
     let handler = path::handler::Handler {
         path,
@@ -1968,9 +2180,16 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// * `paths(...)`  List of method references having attribute [`#[utoipa::path]`][path] macro.
 /// * `components(schemas(...), responses(...))` Takes available _`component`_ configurations. Currently only
 ///    _`schema`_ and _`response`_ components are supported.
-///    * `schemas(...)` List of [`ToSchema`][to_schema]s in OpenAPI schema.
+///    * `schemas(...)` List of [`ToSchema`][to_schema]s in OpenAPI schema. Compilation fails
+///      at the listed type if it does not implement [`ToSchema`][to_schema]. In debug builds
+///      generating the OpenAPI panics if two different schemas are registered with the same name
+///      e.g. two distinct `Error` types. Use _`#[schema(as = ...)]`_ to rename one of them.
+///      The check is a `debug_assert!` and it never fires in release builds where the latter
+///      schema silently overwrites the former one.
 ///    * `responses(...)` List of types that implement [`ToResponse`][to_response_trait].
 /// * `modifiers(...)` List of items implementing [`Modify`][modify] trait for runtime OpenApi modification.
+///   Modifiers are run in declaration order once all paths, components and nested OpenApis are
+///   assembled, so each modifier sees the changes of the previous ones.
 ///   See the [trait documentation][modify] for more details.
 /// * `security(...)` List of [`SecurityRequirement`][security]s global to all operations.
 ///   See more details in [`#[utoipa::path(...)]`][path] [attribute macro security options][path_security].
@@ -1992,6 +2211,11 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///   If more instances is defined only latest one will be rentained.
 ///   See the _[nest(...) attribute syntax below]( #nest-attribute-syntax )_
 ///
+/// * `path_prefix = ...` Prefix prepended to every path of the _`OpenApi`_ including nested
+///   ones e.g. `path_prefix = "/api/v1"` turns `/pets` into `/api/v1/pets`. Leading and trailing
+///   slashes are normalized. It can be [`str`] or an expression such as static [`const`][const]
+///   reference.
+///
 ///
 /// OpenApi derive macro will also derive [`Info`][info] for OpenApi specification using Cargo
 /// environment variables.
@@ -2009,11 +2233,24 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// * `terms_of_service = ...` Define URL to the Terms of Service for the API. It can be [`str`] or an
 ///   expression such as [`include_str!`][include_str] or static [`const`][const] reference. Value
 ///   must be valid URL.
+/// * `summary = ...` Define short summary of the API. It can be [`str`] or an expression such as
+///   [`include_str!`][include_str] or static [`const`][const] reference.
 /// * `description = ...` Define description of the API. Markdown can be used for rich text
 ///   representation. It can be [`str`] or an expression such as [`include_str!`][include_str] or static
 ///   [`const`][const] reference.
 /// * `version = ...` Override default version from _`Cargo.toml`_. Value can be [`str`] or an
 ///   expression such as [`include_str!`][include_str] or static [`const`][const] reference.
+///   Literal [`str`] version not following [semantic versioning](https://semver.org) e.g. `1.0.0`
+///   emits a deprecation warning. The version is still used as is.
+///
+///   ```compile_fail
+///   # #![deny(deprecated)]
+///   # use utoipa::OpenApi;
+///   #[derive(OpenApi)]
+///   // warning: info version `v1` is not a valid semantic version e.g. `1.0.0`
+///   #[openapi(info(version = "v1"))]
+///   struct ApiDoc;
+///   ```
 /// * `contact(...)` Used to override the whole contact generated from environment variables.
 ///     * `name = ...` Define identifying name of contact person / organization. It Can be a literal string.
 ///     * `email = ...` Define email address of the contact person / organization. It can be a literal string.
@@ -2144,6 +2381,19 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// struct ApiDoc;
 /// ```
 ///
+/// _**Types listed in `components(schemas(...))` must implement [`ToSchema`][to_schema]. Compile
+/// error points to the offending type.**_
+/// ```compile_fail,E0277
+/// # use utoipa::OpenApi;
+/// struct Pet {
+///     name: String,
+/// }
+///
+/// #[derive(OpenApi)]
+/// #[openapi(components(schemas(Pet)))] // fail compile cause Pet does not derive ToSchema
+/// struct ApiDoc;
+/// ```
+///
 /// _**Create OpenAPI with reusable response.**_
 /// ```rust
 /// #[derive(utoipa::ToSchema)]
@@ -2242,9 +2492,9 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// deprecated and it will reflect to the generated OpenAPI spec.
 ///
 /// `#[deprecated]` attribute supports adding additional details such as a reason and or since version
-/// but this is is not supported in OpenAPI. OpenAPI has only a boolean flag to determine deprecation.
-/// While it is totally okay to declare deprecated with reason
-/// `#[deprecated  = "There is better way to do this"]` the reason would not render in OpenAPI spec.
+/// but OpenAPI has only a boolean flag to determine deprecation. When a field is declared deprecated
+/// with a reason e.g. `#[deprecated = "There is better way to do this"]` the reason will be appended
+/// to the parameter description as `Deprecated: There is better way to do this`.
 ///
 /// Doc comment on struct fields will be used as description for the generated parameters.
 /// ```rust
@@ -2298,6 +2548,10 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 ///
 /// * `allow_reserved` Defines whether reserved characters _`:/?#[]@!$&'()*+,;=`_ is allowed within value.
 ///
+/// * `deprecated` Can be used to mark the parameter as deprecated in the generated OpenAPI spec but
+///   not in the code. If you'd like to mark the parameter as deprecated in the code as well use
+///   Rust's own `#[deprecated]` attribute instead.
+///
 /// * `example = ...` Can be method reference or _`json!(...)`_. Given example
 ///   will override any example in underlying parameter type.
 ///
@@ -3230,6 +3484,8 @@ impl Parse for ExternalDocs {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE: &str = "unexpected attribute, expected any of: url, description";
 
+        let span = input.span();
+        let mut url = None;
         let mut external_docs = ExternalDocs::default();
 
         while !input.is_empty() {
@@ -3240,7 +3496,7 @@ impl Parse for ExternalDocs {
 
             match attribute_name {
                 "url" => {
-                    external_docs.url = parse_utils::parse_next_literal_str(input)?;
+                    url = Some(parse_utils::parse_next_literal_str(input)?);
                 }
                 "description" => {
                     external_docs.description = Some(parse_utils::parse_next_literal_str(input)?);
@@ -3253,6 +3509,9 @@ impl Parse for ExternalDocs {
             }
         }
 
+        external_docs.url =
+            url.ok_or_else(|| syn::Error::new(span, "missing required attribute: url"))?;
+
         Ok(external_docs)
     }
 }
@@ -3319,6 +3578,16 @@ impl AnyValue {
                         "expected literal value, json!(...) or method reference",
                     )
                 })?;
+                // allow explicit call syntax `MyType::sample()` in addition to `MyType::sample`
+                if input.peek(syn::token::Paren) {
+                    let arguments;
+                    syn::parenthesized!(arguments in input);
+                    if !arguments.is_empty() {
+                        return Err(arguments.error(
+                            "expected method reference without arguments e.g. `MyType::sample()`",
+                        ));
+                    }
+                }
 
                 Ok(AnyValue::Json(quote! { #method() }))
             }
@@ -3335,6 +3604,38 @@ impl AnyValue {
         }
     }
 
+    /// Get span of the user defined value.
+    fn span(&self) -> Span {
+        match self {
+            Self::Json(tokens) | Self::String(tokens) => tokens
+                .clone()
+                .into_iter()
+                .next()
+                .map(|token| token.span())
+                .unwrap_or_else(Span::call_site),
+            Self::DefaultTrait { field_ident, .. } => syn::spanned::Spanned::span(field_ident),
+        }
+    }
+
+    /// Get the literal value if this is a plain literal e.g. `1`, `-1.5` or `"value"`.
+    fn as_lit(&self) -> Option<Lit> {
+        match self {
+            Self::Json(tokens) => match syn::parse2::<syn::Expr>(tokens.clone()).ok()? {
+                syn::Expr::Lit(syn::ExprLit { lit, .. }) => Some(lit),
+                syn::Expr::Unary(syn::ExprUnary {
+                    op: syn::UnOp::Neg(_),
+                    expr,
+                    ..
+                }) => match *expr {
+                    syn::Expr::Lit(syn::ExprLit { lit, .. }) => Some(lit),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
     fn new_default_trait(struct_ident: Ident, field_ident: Member) -> Self {
         Self::DefaultTrait {
             struct_ident,
@@ -3604,8 +3905,26 @@ impl FromIterator<Diagnostics> for Option<Diagnostics> {
     }
 }
 
+/// Emit compile time warning with given `note` pointing to the `span`.
+///
+/// Stable Rust does not allow emitting warnings from proc macros, thus use of deprecated item is
+/// used to warn instead. The returned tokens are statements and must be placed in a block.
+fn deprecation_warning(span: Span, note: &str) -> TokenStream2 {
+    let warning = quote_spanned! {span=> warning };
+
+    quote! {
+        #[deprecated(note = #note)]
+        #[allow(non_upper_case_globals)]
+        const warning: () = ();
+        let _ = #warning;
+    }
+}
+
 trait AttributesExt {
     fn has_deprecated(&self) -> bool;
+
+    /// Get the `note` of `#[deprecated(note = "...")]` or `#[deprecated = "..."]` attribute.
+    fn deprecated_note(&self) -> Option<String>;
 }
 
 impl AttributesExt for Vec<syn::Attribute> {
@@ -3613,6 +3932,11 @@ impl AttributesExt for Vec<syn::Attribute> {
         let this = &**self;
         this.has_deprecated()
     }
+
+    fn deprecated_note(&self) -> Option<String> {
+        let this = &**self;
+        this.deprecated_note()
+    }
 }
 
 impl<'a> AttributesExt for &'a [syn::Attribute] {
@@ -3621,6 +3945,34 @@ impl<'a> AttributesExt for &'a [syn::Attribute] {
             matches!(attr.path().get_ident(), Some(ident) if &*ident.to_string() == "deprecated")
         })
     }
+
+    fn deprecated_note(&self) -> Option<String> {
+        self.iter()
+            .filter(|attr| attr.path().is_ident("deprecated"))
+            .find_map(|attr| match &attr.meta {
+                syn::Meta::NameValue(syn::MetaNameValue {
+                    value:
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(note),
+                            ..
+                        }),
+                    ..
+                }) => Some(note.value()),
+                syn::Meta::List(_) => {
+                    let mut note = None;
+                    let _ = attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("note") {
+                            note = Some(meta.value()?.parse::<LitStr>()?.value());
+                        } else {
+                            let _ = meta.value().and_then(|value| value.parse::<syn::Expr>());
+                        }
+                        Ok(())
+                    });
+                    note
+                }
+                _ => None,
+            })
+    }
 }
 
 #[cfg(test)]
@@ -37,6 +37,7 @@ pub struct OpenApiAttr<'o> {
     external_docs: Option<ExternalDocs>,
     servers: Punctuated<Server, Comma>,
     nested: Vec<NestOpenApi>,
+    path_prefix: Option<parse_utils::LitStrOrExpr>,
 }
 
 impl<'o> OpenApiAttr<'o> {
@@ -65,6 +66,9 @@ impl<'o> OpenApiAttr<'o> {
         if !other.servers.is_empty() {
             self.servers = other.servers;
         }
+        if other.path_prefix.is_some() {
+            self.path_prefix = other.path_prefix;
+        }
 
         self
     }
@@ -82,7 +86,7 @@ pub fn parse_openapi_attrs(attrs: &[Attribute]) -> Result<Option<OpenApiAttr>, E
 impl Parse for OpenApiAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected attribute, expected any of: handlers, components, modifiers, security, tags, external_docs, servers, nest";
+            "unexpected attribute, expected any of: handlers, components, modifiers, security, tags, external_docs, servers, nest, path_prefix";
         let mut openapi = OpenApiAttr::default();
 
         while !input.is_empty() {
@@ -130,6 +134,9 @@ impl Parse for OpenApiAttr<'_> {
                     parenthesized!(nest in input);
                     openapi.nested = parse_utils::parse_groups_collect(&nest)?;
                 }
+                "path_prefix" => {
+                    openapi.path_prefix = Some(parse_utils::parse_next_literal_str_or_expr(input)?);
+                }
                 _ => {
                     return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE));
                 }
@@ -557,6 +564,10 @@ impl ToTokensDiagnostics for OpenApi<'_> {
         let nested_tokens = self
             .nested_tokens()
             .map(|tokens| quote! {openapi = openapi #tokens;});
+        let path_prefix_tokens = attributes
+            .as_ref()
+            .and_then(|attributes| attributes.path_prefix.as_ref())
+            .map(|path_prefix| quote! { openapi.paths.prefix(#path_prefix); });
         tokens.extend(quote! {
             impl utoipa::OpenApi for #ident {
                 fn openapi() -> utoipa::openapi::OpenApi {
@@ -573,8 +584,9 @@ impl ToTokensDiagnostics for OpenApi<'_> {
                         #external_docs
                         .build();
                     #handler_schemas
-                    components.schemas.extend(schemas);
+                    utoipa::__dev::extend_schemas(&mut components.schemas, schemas);
                     #nested_tokens
+                    #path_prefix_tokens
 
                     #modifiers_tokens
 
@@ -649,24 +661,35 @@ impl ToTokensDiagnostics for Components {
             .collect::<Result<Vec<(ComponentSchema, &TypePath)>, Diagnostics>>()?
             .into_iter()
             .fold(
-                quote! { utoipa::openapi::ComponentsBuilder::new() },
-                |mut components, (component_schema, type_path)| {
+                quote! {
+                    let mut schemas = Vec::<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>::new();
+                },
+                |mut schemas, (component_schema, type_path)| {
                     let schema = component_schema.to_token_stream();
                     let name = &component_schema.name_tokens;
 
-                    components.extend(quote! { .schemas_from_iter( {
-                        let mut schemas = Vec::<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>::new();
+                    schemas.extend(quote_spanned! {type_path.span()=>
+                        utoipa::__dev::assert_to_schema::<#type_path>();
+                    });
+                    schemas.extend(quote! {
                         <#type_path as utoipa::ToSchema>::schemas(&mut schemas);
-                        schemas
-                    } )});
-                    components.extend(quote! { .schema(#name, {
-                        let mut generics = Vec::<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>::new();
-                        #schema
-                    }) });
-
-                    components
+                        schemas.push((Into::<String>::into(#name), Into::<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>::into({
+                            let mut generics = Vec::<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>::new();
+                            #schema
+                        })));
+                    });
+
+                    schemas
                 },
             );
+        let builder_tokens = quote! {
+            utoipa::openapi::ComponentsBuilder::new().schemas_from_iter({
+                #builder_tokens
+                let mut components = std::collections::BTreeMap::new();
+                utoipa::__dev::extend_schemas(&mut components, schemas);
+                components
+            })
+        };
 
         let builder_tokens =
             self.responses
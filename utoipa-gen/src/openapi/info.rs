@@ -14,6 +14,7 @@ use crate::parse_utils::{self, LitStrOrExpr};
 pub(super) struct Info<'i> {
     title: Option<LitStrOrExpr>,
     version: Option<LitStrOrExpr>,
+    summary: Option<LitStrOrExpr>,
     description: Option<LitStrOrExpr>,
     terms_of_service: Option<LitStrOrExpr>,
     license: Option<License<'i>>,
@@ -73,6 +74,10 @@ impl Info<'_> {
                 from_env.terms_of_service = info.terms_of_service;
             }
 
+            if info.summary.is_some() {
+                from_env.summary = info.summary;
+            }
+
             if info.description.is_some() {
                 from_env.description = info.description;
             }
@@ -113,6 +118,11 @@ impl Parse for Info<'_> {
                         input.parse::<LitStrOrExpr>()
                     })?)
                 }
+                "summary" => {
+                    info.summary = Some(parse_utils::parse_next(input, || {
+                        input.parse::<LitStrOrExpr>()
+                    })?)
+                }
                 "description" => {
                     info.description = Some(parse_utils::parse_next(input, || {
                         input.parse::<LitStrOrExpr>()
@@ -134,7 +144,7 @@ impl Parse for Info<'_> {
                     info.contact = Some(contact_stream.parse()?)
                 }
                 _ => {
-                    return Err(Error::new(ident.span(), format!("unexpected attribute: {attribute_name}, expected one of: title, terms_of_service, version, description, license, contact")));
+                    return Err(Error::new(ident.span(), format!("unexpected attribute: {attribute_name}, expected one of: title, terms_of_service, version, summary, description, license, contact")));
                 }
             }
             if !input.is_empty() {
@@ -149,14 +159,30 @@ impl Parse for Info<'_> {
 impl ToTokens for Info<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let title = self.title.as_ref().map(|title| quote! { .title(#title) });
-        let version = self
-            .version
-            .as_ref()
-            .map(|version| quote! { .version(#version) });
+        let version = self.version.as_ref().map(|version| match version {
+            LitStrOrExpr::LitStr(lit) if !is_semver(&lit.value()) => {
+                let note = format!(
+                    "info version `{}` is not a valid semantic version e.g. `1.0.0`",
+                    lit.value()
+                );
+                let warning = crate::deprecation_warning(lit.span(), &note);
+                quote! {
+                    .version({
+                        #warning
+                        #version
+                    })
+                }
+            }
+            _ => quote! { .version(#version) },
+        });
         let terms_of_service = self
             .terms_of_service
             .as_ref()
             .map(|terms_of_service| quote! {.terms_of_service(Some(#terms_of_service))});
+        let summary = self
+            .summary
+            .as_ref()
+            .map(|summary| quote! { .summary(Some(#summary)) });
         let description = self
             .description
             .as_ref()
@@ -175,6 +201,7 @@ impl ToTokens for Info<'_> {
                 #title
                 #version
                 #terms_of_service
+                #summary
                 #description
                 #license
                 #contact
@@ -369,10 +396,101 @@ fn get_parsed_author(author: Option<&str>) -> Option<(&str, &str)> {
     })
 }
 
+/// Check whether given version is a [semantic version](https://semver.org) e.g. `1.0.0`,
+/// `1.0.0-alpha.1` or `1.0.0+build.5`.
+fn is_semver(version: &str) -> bool {
+    let is_number = |number: &str| {
+        !number.is_empty()
+            && number.chars().all(|c| c.is_ascii_digit())
+            && (number == "0" || !number.starts_with('0'))
+    };
+    let is_identifiers = |identifiers: &str| {
+        identifiers.split('.').all(|identifier| {
+            !identifier.is_empty()
+                && identifier
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+    };
+
+    let (version, build) = match version.split_once('+') {
+        Some((version, build)) => (version, Some(build)),
+        None => (version, None),
+    };
+    let (core, pre_release) = match version.split_once('-') {
+        Some((core, pre_release)) => (core, Some(pre_release)),
+        None => (version, None),
+    };
+    let core = core.split('.').collect::<Vec<_>>();
+
+    core.len() == 3
+        && core.iter().all(|number| is_number(number))
+        && pre_release.map_or(true, is_identifiers)
+        && build.map_or(true, is_identifiers)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn semver_version_check() {
+        for version in [
+            "0.1.0",
+            "1.0.0",
+            "10.20.30",
+            "1.0.0-alpha.1",
+            "1.0.0+build.5",
+            "1.0.0-rc-1+001",
+        ] {
+            assert!(
+                is_semver(version),
+                "{version} should be valid semantic version"
+            );
+        }
+        for version in [
+            "",
+            "1",
+            "1.0",
+            "v1.0.0",
+            "1.0.0.0",
+            "01.0.0",
+            "1.0.0-",
+            "1.0.0+",
+            "1.0.0-alpha..1",
+            "latest",
+        ] {
+            assert!(
+                !is_semver(version),
+                "{version} should not be valid semantic version"
+            );
+        }
+    }
+
+    #[test]
+    fn info_with_non_semver_version_emits_deprecated_note() {
+        let info = syn::parse2::<Info>(quote! { version = "latest" }).unwrap();
+
+        let tokens = info.to_token_stream().to_string();
+
+        assert!(
+            tokens.contains("info version `latest` is not a valid semantic version e.g. `1.0.0`"),
+            "expected invalid version note in: {tokens}"
+        );
+    }
+
+    #[test]
+    fn info_with_semver_version_emits_no_warning() {
+        let info = syn::parse2::<Info>(quote! { version = "1.0.0-rc.1" }).unwrap();
+
+        let tokens = info.to_token_stream().to_string();
+
+        assert!(
+            !tokens.contains("deprecated"),
+            "expected no invalid version warning in: {tokens}"
+        );
+    }
+
     #[test]
     fn parse_author_with_email_success() {
         let author = "Tessu Tester <tessu@steps.com>";
@@ -8,16 +8,21 @@ use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
 use syn::{parenthesized, parse::Parse, Token};
-use syn::{Expr, ExprLit, Lit, LitStr};
+use syn::{Expr, ExprLit, Lit, LitStr, Type};
 
 use crate::component::{ComponentSchema, GenericType, TypeTree};
 use crate::{
-    as_tokens_or_diagnostics, parse_utils, Deprecated, Diagnostics, OptionExt, ToTokensDiagnostics,
+    as_tokens_or_diagnostics, parse_utils, Deprecated, Diagnostics, ExternalDocs, OptionExt,
+    ToTokensDiagnostics,
 };
 use crate::{schema_type::SchemaType, security_requirement::SecurityRequirementsAttr, Array};
 
 use self::response::Response;
-use self::{parameter::Parameter, request_body::RequestBodyAttr, response::Responses};
+use self::{
+    parameter::{Parameter, ValueParameter},
+    request_body::RequestBodyAttr,
+    response::Responses,
+};
 
 pub mod example;
 pub mod handler;
@@ -42,6 +47,8 @@ pub fn format_path_ident(fn_name: Cow<'_, Ident>) -> Cow<'_, Ident> {
 pub struct PathAttr<'p> {
     methods: Vec<HttpMethod>,
     request_body: Option<RequestBodyAttr<'p>>,
+    request_body_from_arg: bool,
+    mirror_request_content_type: bool,
     responses: Vec<Response<'p>>,
     pub(super) path: Option<parse_utils::LitStrOrExpr>,
     operation_id: Option<Expr>,
@@ -53,6 +60,7 @@ pub struct PathAttr<'p> {
     impl_for: Option<Ident>,
     description: Option<parse_utils::LitStrOrExpr>,
     summary: Option<parse_utils::LitStrOrExpr>,
+    external_docs: Option<ExternalDocs>,
 }
 
 impl<'p> PathAttr<'p> {
@@ -62,6 +70,83 @@ impl<'p> PathAttr<'p> {
             .push(Response::IntoResponses(Cow::Borrowed(ty)))
     }
 
+    /// Infer request body from the last handler argument which is not declared as parameter
+    /// when `request_body_from_arg` is defined and no `request_body` is defined.
+    ///
+    /// This is best-effort: references are dereferenced and `Json<T>` is unwrapped to `T`.
+    pub fn update_request_body_from_args(&mut self, inputs: &Punctuated<syn::FnArg, Comma>) {
+        use self::media_type::{DefaultSchema, ParsedType, Schema};
+
+        if !self.request_body_from_arg || self.request_body.is_some() {
+            return;
+        }
+
+        let is_parameter = |pat_type: &syn::PatType| {
+            let name = match &*pat_type.pat {
+                syn::Pat::Ident(pat_ident) => Some(pat_ident.ident.to_string()),
+                _ => None,
+            };
+            let type_ident = match &*pat_type.ty {
+                Type::Path(type_path) => {
+                    type_path.path.segments.last().map(|segment| &segment.ident)
+                }
+                _ => None,
+            };
+
+            self.params.iter().any(|parameter| match parameter {
+                Parameter::Value(value) => name.as_deref() == Some(&*value.name),
+                Parameter::IntoParamsIdent(into_params) => {
+                    into_params
+                        .path
+                        .segments
+                        .last()
+                        .map(|segment| &segment.ident)
+                        == type_ident
+                }
+            })
+        };
+
+        let body = inputs
+            .iter()
+            .filter_map(|input| match input {
+                syn::FnArg::Typed(pat_type) if !is_parameter(pat_type) => Some(&*pat_type.ty),
+                _ => None,
+            })
+            .last();
+
+        if let Some(ty) = body.map(request_body_arg_type) {
+            self.request_body = Some(RequestBodyAttr::from_schema(Schema::Default(
+                DefaultSchema::TypePath(ParsedType {
+                    ty: Cow::Owned(ty.clone()),
+                    is_inline: false,
+                }),
+            )));
+        }
+    }
+
+    /// Use content type of the request body as content type of responses that do not define one
+    /// when `mirror_request_content_type` is defined.
+    ///
+    /// Nothing is mirrored if request body has more than one content type.
+    pub fn update_responses_content_type(&mut self) -> Result<(), Diagnostics> {
+        if !self.mirror_request_content_type {
+            return Ok(());
+        }
+
+        let content_type = self
+            .request_body
+            .as_ref()
+            .map_try(RequestBodyAttr::get_content_type)?
+            .flatten();
+        if let Some(content_type) = content_type {
+            self.responses
+                .iter_mut()
+                .for_each(|response| response.set_default_content_type(&content_type));
+        }
+
+        Ok(())
+    }
+
     #[cfg(any(
         feature = "actix_extras",
         feature = "rocket_extras",
@@ -107,7 +192,7 @@ impl<'p> PathAttr<'p> {
 
 impl Parse for PathAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: method, get, post, put, delete, options, head, patch, trace, operation_id, path, request_body, responses, params, tag, security, context_path, description, summary";
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: method, get, post, put, delete, options, head, patch, trace, operation_id, path, request_body, request_body_from_arg, mirror_request_content_type, responses, params, tag, security, context_path, description, summary, external_docs";
         let mut path_attr = PathAttr::default();
 
         while !input.is_empty() {
@@ -136,6 +221,12 @@ impl Parse for PathAttr<'_> {
                 "request_body" => {
                     path_attr.request_body = Some(input.parse::<RequestBodyAttr>()?);
                 }
+                "request_body_from_arg" => {
+                    path_attr.request_body_from_arg = true;
+                }
+                "mirror_request_content_type" => {
+                    path_attr.mirror_request_content_type = true;
+                }
                 "responses" => {
                     let responses;
                     parenthesized!(responses in input);
@@ -182,6 +273,11 @@ impl Parse for PathAttr<'_> {
                 "summary" => {
                     path_attr.summary = Some(parse_utils::parse_next_literal_str_or_expr(input)?)
                 }
+                "external_docs" => {
+                    let external_docs;
+                    parenthesized!(external_docs in input);
+                    path_attr.external_docs = Some(external_docs.parse()?);
+                }
                 _ => {
                     if let Some(path_operation) =
                         attribute_name.parse::<HttpMethod>().into_iter().next()
@@ -198,10 +294,138 @@ impl Parse for PathAttr<'_> {
             }
         }
 
+        path_attr.validate_path_parameters()?;
+
         Ok(path_attr)
     }
 }
 
+/// Resolve the type of request body argument by dereferencing references and unwrapping
+/// `Json<T>` to `T`.
+fn request_body_arg_type(ty: &Type) -> &Type {
+    match ty {
+        Type::Reference(reference) => request_body_arg_type(&reference.elem),
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .filter(|segment| segment.ident == "Json")
+            .and_then(|segment| match &segment.arguments {
+                syn::PathArguments::AngleBracketed(arguments) => arguments.args.first(),
+                _ => None,
+            })
+            .and_then(|argument| match argument {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .unwrap_or(ty),
+        _ => ty,
+    }
+}
+
+impl PathAttr<'_> {
+    /// Get the literal `path` prefixed with the literal `context_path` if defined. `None` is
+    /// returned if either of them is an expression as it cannot be resolved at compile time.
+    fn literal_path(&self) -> Option<(String, Span)> {
+        let Some(parse_utils::LitStrOrExpr::LitStr(path)) = &self.path else {
+            return None;
+        };
+
+        match &self.context_path {
+            Some(parse_utils::LitStrOrExpr::LitStr(context_path)) => Some((
+                format!("{}{}", context_path.value(), path.value()),
+                path.span(),
+            )),
+            Some(parse_utils::LitStrOrExpr::Expr(_)) => None,
+            None => Some((path.value(), path.span())),
+        }
+    }
+
+    fn path_parameters(&self) -> impl Iterator<Item = &ValueParameter<'_>> {
+        self.params.iter().filter_map(|parameter| match parameter {
+            Parameter::Value(value) if value.is_defined_in_path() => Some(value),
+            _ => None,
+        })
+    }
+
+    /// Validate that every `{placeholder}` of the literal `path` and `context_path` has a
+    /// parameter declared with `Path` location in `params(...)` when at least one path parameter
+    /// is declared, unless parameters can be resolved elsewhere e.g. from `IntoParams` types or
+    /// handler arguments with framework extras.
+    fn validate_path_parameters(&self) -> syn::Result<()> {
+        let Some((path, span)) = self.literal_path() else {
+            return Ok(());
+        };
+
+        let resolved_elsewhere = cfg!(any(
+            feature = "actix_extras",
+            feature = "rocket_extras",
+            feature = "axum_extras"
+        )) || self
+            .params
+            .iter()
+            .any(|parameter| matches!(parameter, Parameter::IntoParamsIdent(_)));
+        if resolved_elsewhere || self.path_parameters().next().is_none() {
+            return Ok(());
+        }
+
+        if let Some(placeholder) = path_placeholders(&path).into_iter().find(|placeholder| {
+            !self
+                .path_parameters()
+                .any(|parameter| parameter.name == *placeholder)
+        }) {
+            return Err(syn::Error::new(
+                span,
+                format!("path placeholder `{{{placeholder}}}` has no matching path parameter in `params(...)`"),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Warn about parameters declared with `Path` location in `params(...)` that do not match any
+    /// `{placeholder}` of the literal `path` and `context_path`. This is not an error since the
+    /// placeholder might be defined by a prefix the path is nested under in `OpenApi` derive.
+    fn unknown_path_parameter_warnings(&self) -> TokenStream2 {
+        let Some((path, _)) = self.literal_path() else {
+            return TokenStream2::new();
+        };
+        let placeholders = path_placeholders(&path);
+
+        self.path_parameters()
+            .filter(|parameter| !placeholders.contains(&&*parameter.name))
+            .map(|parameter| {
+                let note = unknown_path_parameter_note(&parameter.name, &path);
+                let warning = crate::deprecation_warning(
+                    parameter.name_span().unwrap_or_else(Span::call_site),
+                    &note,
+                );
+
+                quote! {{ #warning }}
+            })
+            .collect()
+    }
+}
+
+fn unknown_path_parameter_note(name: &str, path: &str) -> String {
+    format!("path parameter `{name}` does not match any placeholder in path `{path}`")
+}
+
+/// Get names of `{placeholder}`s in path template. Possible regex e.g. `{id:\d+}` and wildcard
+/// e.g. `{*rest}` markers are stripped from the name.
+fn path_placeholders(path: &str) -> Vec<&str> {
+    path.split('{')
+        .skip(1)
+        .filter_map(|segment| segment.split_once('}'))
+        .map(|(placeholder, _)| {
+            let placeholder = placeholder
+                .split_once(':')
+                .map_or(placeholder, |(name, _)| name);
+            placeholder.trim_start_matches('*').trim()
+        })
+        .collect()
+}
+
 /// Path operation HTTP method
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub enum HttpMethod {
@@ -293,6 +517,7 @@ pub struct Path<'p> {
     path: Option<String>,
     doc_comments: Option<Vec<String>>,
     deprecated: bool,
+    deprecated_note: Option<String>,
 }
 
 impl<'p> Path<'p> {
@@ -304,6 +529,7 @@ impl<'p> Path<'p> {
             path: None,
             doc_comments: None,
             deprecated: false,
+            deprecated_note: None,
         }
     }
 
@@ -330,6 +556,12 @@ impl<'p> Path<'p> {
 
         self
     }
+
+    pub fn deprecated_note(mut self, deprecated_note: Option<String>) -> Self {
+        self.deprecated_note = deprecated_note;
+
+        self
+    }
 }
 
 impl<'p> ToTokensDiagnostics for Path<'p> {
@@ -465,6 +697,7 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
 
         let operation: Operation = Operation {
             deprecated: self.deprecated,
+            deprecated_note: self.deprecated_note.as_deref(),
             operation_id,
             summary,
             description,
@@ -472,6 +705,7 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             request_body: self.path_attr.request_body.as_ref(),
             responses: self.path_attr.responses.as_ref(),
             security: self.path_attr.security.as_ref(),
+            external_docs: self.path_attr.external_docs.as_ref(),
         };
         let operation = as_tokens_or_diagnostics!(&operation);
 
@@ -504,7 +738,7 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             schemas
         }
 
-        let response_schemas = self
+        let response_component_schemas = self
             .path_attr
             .responses
             .iter()
@@ -512,15 +746,40 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             .collect::<Result<Vec<_>, Diagnostics>>()?
             .into_iter()
             .flatten()
-            .fold(TokenStream2::new(), to_schema_references);
+            .collect::<Vec<_>>();
 
-        let schemas = self
+        let request_body_component_schemas = self
             .path_attr
             .request_body
             .as_ref()
             .map_try(|request_body| request_body.get_component_schemas())?
             .into_iter()
             .flatten()
+            .collect::<Vec<_>>();
+
+        let body_assertions = body_to_schema_assertions(
+            response_component_schemas
+                .iter()
+                .chain(&request_body_component_schemas)
+                .map(|(_, component_schema)| component_schema),
+        );
+
+        let response_schemas = response_component_schemas
+            .into_iter()
+            .fold(TokenStream2::new(), to_schema_references);
+
+        let schemas = request_body_component_schemas
+            .into_iter()
+            .fold(TokenStream2::new(), to_schema_references);
+
+        let parameter_schemas = self
+            .path_attr
+            .params
+            .iter()
+            .map(Parameter::get_component_schema)
+            .collect::<Result<Vec<_>, Diagnostics>>()?
+            .into_iter()
+            .flatten()
             .fold(TokenStream2::new(), to_schema_references);
 
         let mut tags = self.path_attr.tags.clone();
@@ -585,6 +844,16 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             path_struct
         };
 
+        let unknown_path_parameter_warnings = self.path_attr.unknown_path_parameter_warnings();
+
+        if !body_assertions.is_empty() {
+            tokens.extend(quote! {
+                const _: () = {
+                    #body_assertions
+                };
+            });
+        }
+
         tokens.extend(quote! {
             impl<'t> utoipa::__dev::Tags<'t> for #impl_for {
                 fn tags() -> Vec<&'t str> {
@@ -593,6 +862,7 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             }
             impl utoipa::Path for #impl_for {
                 fn path() -> String {
+                    #unknown_path_parameter_warnings
                     #path_with_context_path
                 }
 
@@ -609,6 +879,7 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
 
             impl utoipa::__dev::SchemaReferences for #impl_for {
                 fn schemas(schemas: &mut Vec<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>) {
+                    #parameter_schemas
                     #schemas
                     #response_schemas
                 }
@@ -620,16 +891,34 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
     }
 }
 
+/// Assert that every type referenced from _`body`_ implements `ToSchema`. The assertion is
+/// spanned to the type so a missing `ToSchema` derive is reported at the type in question.
+fn body_to_schema_assertions<'c>(
+    component_schemas: impl Iterator<Item = &'c ComponentSchema>,
+) -> TokenStream2 {
+    component_schemas
+        .flat_map(|component_schema| &component_schema.schema_references)
+        .filter_map(|reference| reference.path.as_ref())
+        .map(|type_path| {
+            quote_spanned! {type_path.span()=>
+                utoipa::__dev::assert_to_schema::<#type_path>();
+            }
+        })
+        .collect()
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Operation<'a> {
     operation_id: Expr,
     summary: Option<Summary<'a>>,
     description: Option<Description<'a>>,
     deprecated: bool,
+    deprecated_note: Option<&'a str>,
     parameters: &'a Vec<Parameter<'a>>,
     request_body: Option<&'a RequestBodyAttr<'a>>,
     responses: &'a Vec<Response<'a>>,
     security: Option<&'a Array<'a, SecurityRequirementsAttr>>,
+    external_docs: Option<&'a ExternalDocs>,
 }
 
 impl ToTokensDiagnostics for Operation<'_> {
@@ -667,8 +956,18 @@ impl ToTokensDiagnostics for Operation<'_> {
             summary.to_tokens(tokens);
         }
 
-        if let Some(description) = &self.description {
-            description.to_tokens(tokens);
+        match (&self.description, self.deprecated_note) {
+            (description, Some(note)) => {
+                description_with_deprecated_note(description.as_ref(), note).to_tokens(tokens)
+            }
+            (Some(description), None) => description.to_tokens(tokens),
+            (None, None) => (),
+        }
+
+        if let Some(external_docs) = self.external_docs {
+            tokens.extend(quote! {
+                .external_docs(Some(#external_docs))
+            })
         }
 
         for parameter in self.parameters {
@@ -704,6 +1003,22 @@ impl ToTokens for Description<'_> {
     }
 }
 
+/// Append the `note` of `#[deprecated(note = "...")]` to the operation description as a separate
+/// paragraph.
+fn description_with_deprecated_note(description: Option<&Description>, note: &str) -> TokenStream2 {
+    let note = format!("Deprecated: {note}");
+    match description {
+        Some(Description::Value(value)) => quote! {
+            .description(Some(format!("{}\n\n{}", #value, #note)))
+        },
+        Some(Description::Vec(vec)) if !vec.join("").is_empty() => {
+            let description = format!("{}\n\n{note}", vec.join("\n\n"));
+            quote! { .description(Some(#description)) }
+        }
+        _ => quote! { .description(Some(#note)) },
+    }
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 enum Summary<'a> {
     Value(&'a parse_utils::LitStrOrExpr),
@@ -809,3 +1124,87 @@ mod parse {
         parse_utils::parse_comma_separated_within_parenthesis(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::{body_to_schema_assertions, PathAttr, RequestBodyAttr};
+
+    fn request_body_assertions(request_body: proc_macro2::TokenStream) -> String {
+        let request_body = syn::parse2::<RequestBodyAttr>(request_body).unwrap();
+        let component_schemas = request_body
+            .get_component_schemas()
+            .unwrap()
+            .collect::<Vec<_>>();
+
+        body_to_schema_assertions(
+            component_schemas
+                .iter()
+                .map(|(_, component_schema)| component_schema),
+        )
+        .to_string()
+    }
+
+    #[test]
+    fn body_type_asserts_to_schema() {
+        assert_eq!(
+            request_body_assertions(quote!(= Vec<Pet>)),
+            quote!(utoipa::__dev::assert_to_schema::<Pet>();).to_string()
+        );
+    }
+
+    #[test]
+    fn primitive_body_type_has_no_to_schema_assertion() {
+        assert_eq!(request_body_assertions(quote!(= String)), "");
+    }
+
+    #[test]
+    #[cfg(not(any(
+        feature = "actix_extras",
+        feature = "rocket_extras",
+        feature = "axum_extras"
+    )))]
+    fn path_placeholder_without_path_parameter_is_rejected() {
+        let error = syn::parse2::<PathAttr>(quote!(
+            get,
+            context_path = "/tenants/{tenant}",
+            path = "/pets/{id}",
+            params(("id" = u64, Path))
+        ))
+        .err()
+        .expect("placeholder without path parameter should fail");
+
+        assert_eq!(
+            error.to_string(),
+            "path placeholder `{tenant}` has no matching path parameter in `params(...)`"
+        );
+    }
+
+    #[test]
+    fn path_parameter_without_placeholder_emits_deprecated_note() {
+        let path_attr =
+            syn::parse2::<PathAttr>(quote!(get, path = "/pets", params(("id" = u64, Path))))
+                .unwrap();
+
+        let warnings = path_attr.unknown_path_parameter_warnings().to_string();
+
+        assert!(
+            warnings.contains("path parameter `id` does not match any placeholder in path `/pets`"),
+            "expected unknown path parameter note in: {warnings}"
+        );
+    }
+
+    #[test]
+    fn path_parameters_are_not_checked_with_expression_context_path() {
+        let path_attr = syn::parse2::<PathAttr>(quote!(
+            get,
+            context_path = CONTEXT_PATH,
+            path = "/pets/{id}",
+            params(("tenant" = String, Path))
+        ))
+        .unwrap();
+
+        assert!(path_attr.unknown_path_parameter_warnings().is_empty());
+    }
+}
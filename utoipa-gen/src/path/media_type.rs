@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::token::{Comma, Paren};
@@ -24,6 +25,7 @@ use encoding::Encoding;
 /// Parse OpenAPI Media Type object params
 /// ( Schema )
 /// ( Schema = "content/type" )
+/// ( object(name = Schema, ...) )
 /// ( "content/type", ),
 /// ( "content/type", example = ..., examples(..., ...), encoding(("exampleField" = (...)), ...) )
 #[derive(Default)]
@@ -241,6 +243,18 @@ impl Schema<'_> {
         }
     }
 
+    /// Get `ComponentSchema`s of the schema along with info whether the schema is inlined.
+    /// Inline object has one `ComponentSchema` per property.
+    pub fn get_component_schemas(&self) -> Result<Vec<(bool, ComponentSchema)>, Diagnostics> {
+        match self {
+            Self::Default(DefaultSchema::Object(object)) => object.get_component_schemas(),
+            _ => Ok(Some(self.is_inline())
+                .zip(self.get_component_schema()?)
+                .into_iter()
+                .collect()),
+        }
+    }
+
     pub fn is_inline(&self) -> bool {
         match self {
             Self::Default(def) => match def {
@@ -268,6 +282,8 @@ impl ToTokensDiagnostics for Schema<'_> {
 pub enum DefaultSchema<'d> {
     Ref(parse_utils::LitStrOrExpr),
     TypePath(ParsedType<'d>),
+    /// Anonymous object defined in place with `object(name = Type, ...)`.
+    Object(InlineObject<'d>),
     /// for cases where the schema is irrelevant but we just want to return generic
     /// `content_type` without actual schema.
     #[default]
@@ -301,6 +317,7 @@ impl ToTokensDiagnostics for DefaultSchema<'_> {
 
                 component_tokens.to_tokens(tokens);
             }
+            Self::Object(object) => object.to_tokens(tokens)?,
             Self::Raw {
                 tokens: raw_tokens, ..
             } => {
@@ -341,7 +358,7 @@ impl DefaultSchema<'_> {
                 let type_tree = path.to_type_tree()?;
                 Ok(type_tree.get_default_content_type())
             }
-            Self::Ref(_) => Ok(Cow::Borrowed("application/json")),
+            Self::Ref(_) | Self::Object(_) => Ok(Cow::Borrowed("application/json")),
             Self::Raw { ty, .. } => {
                 let type_tree = TypeTree::from_type(ty.as_ref())?;
                 Ok(type_tree.get_default_content_type())
@@ -381,12 +398,25 @@ impl Parse for DefaultSchema<'_> {
             false
         };
 
+        let fork = input.fork();
+        let is_object = if let Some(ident) = fork.parse::<Option<syn::Ident>>()? {
+            ident == "object" && fork.peek(Paren)
+        } else {
+            false
+        };
+
         if is_ref {
             input.parse::<Token![ref]>()?;
             let ref_stream;
             syn::parenthesized!(ref_stream in input);
 
             ref_stream.parse().map(Self::Ref)
+        } else if is_object {
+            input.parse::<syn::Ident>()?;
+            let object;
+            syn::parenthesized!(object in input);
+
+            object.parse().map(Self::Object)
         } else {
             input.parse().map(Self::TypePath)
         }
@@ -438,3 +468,88 @@ impl Parse for ParsedType<'_> {
         })
     }
 }
+
+// object(name = Type, "name" = inline(Type), ...)
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct InlineObject<'o> {
+    properties: Vec<InlineObjectProperty<'o>>,
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct InlineObjectProperty<'p> {
+    name: String,
+    ty: ParsedType<'p>,
+}
+
+impl InlineObject<'_> {
+    fn get_component_schemas(&self) -> Result<Vec<(bool, ComponentSchema)>, Diagnostics> {
+        self.properties
+            .iter()
+            .map(|property| {
+                let type_tree = property.ty.to_type_tree()?;
+                Ok(Some(property.ty.is_inline).zip(type_tree.get_component_schema()?))
+            })
+            .collect::<Result<Vec<_>, Diagnostics>>()
+            .map(|component_schemas| component_schemas.into_iter().flatten().collect())
+    }
+}
+
+impl Parse for InlineObject<'_> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let properties = Punctuated::<InlineObjectProperty, Comma>::parse_terminated(input)?;
+
+        Ok(Self {
+            properties: properties.into_iter().collect(),
+        })
+    }
+}
+
+impl Parse for InlineObjectProperty<'_> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = if input.peek(syn::LitStr) {
+            input.parse::<syn::LitStr>()?.value()
+        } else {
+            input
+                .call(Ident::parse_any)
+                .map_err(|error| {
+                    Error::new(
+                        error.span(),
+                        format!("expected property name e.g. `token = String`, {error}"),
+                    )
+                })?
+                .to_string()
+        };
+        input.parse::<Token![=]>()?;
+
+        Ok(Self {
+            name,
+            ty: input.parse()?,
+        })
+    }
+}
+
+impl ToTokensDiagnostics for InlineObject<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
+        tokens.extend(quote! { utoipa::openapi::ObjectBuilder::new() });
+
+        for InlineObjectProperty { name, ty } in &self.properties {
+            let type_tree = ty.to_type_tree()?;
+            let schema = ComponentSchema::new(ComponentSchemaProps {
+                type_tree: &type_tree,
+                features: vec![Inline::from(ty.is_inline).into()],
+                description: None,
+                container: &Container {
+                    generics: &Generics::default(),
+                },
+            })?
+            .to_token_stream();
+
+            tokens.extend(quote! { .property(#name, #schema) });
+            if !type_tree.is_option() {
+                tokens.extend(quote! { .required(#name) });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -26,10 +26,12 @@ use crate::{
         },
         ComponentSchema, Container, TypeTree,
     },
-    parse_utils, Diagnostics, Required, ToTokensDiagnostics,
+    parse_utils,
+    schema_type::SchemaType,
+    Diagnostics, Required, ToTokensDiagnostics,
 };
 
-use super::media_type::ParsedType;
+use super::media_type::{MediaTypePathExt, ParsedType};
 
 /// Parameter of request such as in path, header, query or cookie
 ///
@@ -77,6 +79,21 @@ impl<'p> Parameter<'p> {
     }
 }
 
+impl Parameter<'_> {
+    /// Get [`ComponentSchema`] of the parameter type along with inline status of the type. Only
+    /// value parameters with defined type have a schema. For `IntoParams` parameters the schemas
+    /// are resolved by the `IntoParams` implementation itself.
+    pub fn get_component_schema(&self) -> Result<Option<(bool, ComponentSchema)>, Diagnostics> {
+        match self {
+            Self::Value(ValueParameter {
+                parameter_schema: Some(parameter_schema),
+                ..
+            }) => parameter_schema.get_component_schema(),
+            _ => Ok(None),
+        }
+    }
+}
+
 impl Parse for Parameter<'_> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         if input.fork().parse::<TypePath>().is_ok() {
@@ -228,6 +245,27 @@ impl ToTokensDiagnostics for ParameterSchema<'_> {
     }
 }
 
+impl ParameterSchema<'_> {
+    fn get_component_schema(&self) -> Result<Option<(bool, ComponentSchema)>, Diagnostics> {
+        match &self.parameter_type {
+            #[cfg(any(
+                feature = "actix_extras",
+                feature = "rocket_extras",
+                feature = "axum_extras"
+            ))]
+            ParameterType::External(type_tree) => Ok(type_tree
+                .get_component_schema()?
+                .map(|component_schema| (false, component_schema))),
+            ParameterType::Parsed(parsed_type) => {
+                let type_tree = TypeTree::from_type(parsed_type.ty.as_ref())?;
+                Ok(type_tree
+                    .get_component_schema()?
+                    .map(|component_schema| (parsed_type.is_inline, component_schema)))
+            }
+        }
+    }
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 enum ParameterType<'p> {
     #[cfg(any(
@@ -243,11 +281,26 @@ enum ParameterType<'p> {
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct ValueParameter<'a> {
     pub name: Cow<'a, str>,
+    name_span: Option<proc_macro2::Span>,
     parameter_in: ParameterIn,
+    /// Whether `parameter_in` is explicitly defined in the path macro.
+    parameter_in_defined: bool,
     parameter_schema: Option<ParameterSchema<'a>>,
     features: (Vec<Feature>, Vec<Feature>),
 }
 
+impl ValueParameter<'_> {
+    /// Check whether parameter is explicitly defined to be located in path.
+    pub fn is_defined_in_path(&self) -> bool {
+        self.parameter_in_defined && self.parameter_in == ParameterIn::Path
+    }
+
+    /// Get span of the parameter name if it is defined in the path macro.
+    pub fn name_span(&self) -> Option<proc_macro2::Span> {
+        self.name_span
+    }
+}
+
 impl PartialEq for ValueParameter<'_> {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name && self.parameter_in == other.parameter_in
@@ -265,8 +318,9 @@ impl Parse for ValueParameter<'_> {
 
         if input.peek(LitStr) {
             // parse name
-            let name = input.parse::<LitStr>()?.value();
-            parameter.name = Cow::Owned(name);
+            let name = input.parse::<LitStr>()?;
+            parameter.name = Cow::Owned(name.value());
+            parameter.name_span = Some(name.span());
 
             if input.peek(Token![=]) {
                 parameter.parameter_schema = Some(ParameterSchema {
@@ -290,6 +344,7 @@ impl Parse for ValueParameter<'_> {
 
         if input.fork().parse::<ParameterIn>().is_ok() {
             parameter.parameter_in = input.parse()?;
+            parameter.parameter_in_defined = true;
             if !input.is_empty() {
                 input.parse::<Token![,]>()?;
             }
@@ -299,6 +354,18 @@ impl Parse for ValueParameter<'_> {
             .parse::<ParameterFeatures>()?
             .split_for_parameter_type();
 
+        if let Some(ParameterSchema {
+            parameter_type: ParameterType::Parsed(parsed_type),
+            ..
+        }) = &parameter.parameter_schema
+        {
+            for feature in &parameter_features {
+                if let Feature::Example(example) = feature {
+                    validate_example_type(&parsed_type.ty, example)?;
+                }
+            }
+        }
+
         parameter.features = (schema_features.clone(), parameter_features);
         if let Some(parameter_schema) = &mut parameter.parameter_schema {
             parameter_schema.features = schema_features;
@@ -312,6 +379,72 @@ impl Parse for ValueParameter<'_> {
     }
 }
 
+/// Validate that literal `example` matches the type of primitive parameter. E.g. string example
+/// is not allowed for integer parameter. Examples defined with `json!(...)` or method reference
+/// and parameters of non primitive types are not validated.
+fn validate_example_type(ty: &syn::Type, example: &Example) -> syn::Result<()> {
+    fn primitive_schema_type(ty: &syn::Type) -> Option<SchemaType<'_>> {
+        match ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                let last_segment = type_path.path.segments.last()?;
+                if last_segment.ident == "Option" {
+                    match &last_segment.arguments {
+                        syn::PathArguments::AngleBracketed(arguments) => {
+                            match arguments.args.first()? {
+                                syn::GenericArgument::Type(ty) => primitive_schema_type(ty),
+                                _ => None,
+                            }
+                        }
+                        _ => None,
+                    }
+                } else {
+                    let schema_type = SchemaType {
+                        path: Cow::Borrowed(&type_path.path),
+                        nullable: false,
+                    };
+                    schema_type.is_primitive().then_some(schema_type)
+                }
+            }
+            syn::Type::Reference(reference) => primitive_schema_type(&reference.elem),
+            _ => None,
+        }
+    }
+
+    let (Some(schema_type), Some(lit)) = (primitive_schema_type(ty), example.as_lit()) else {
+        return Ok(());
+    };
+    let is_bool = schema_type.path.is_ident("bool");
+    let expected = if is_bool {
+        "boolean"
+    } else if schema_type.is_integer() {
+        "integer"
+    } else if schema_type.is_number() {
+        "number"
+    } else {
+        "string"
+    };
+
+    let matches = match &lit {
+        syn::Lit::Str(_) => expected == "string",
+        syn::Lit::Int(_) => schema_type.is_number(),
+        syn::Lit::Float(_) => expected == "number",
+        syn::Lit::Bool(_) => is_bool,
+        _ => true,
+    };
+
+    if matches {
+        Ok(())
+    } else {
+        Err(Error::new(
+            lit.span(),
+            format!(
+                "example type does not match the parameter type, expected {expected} example for `{}`",
+                ty.to_token_stream()
+            ),
+        ))
+    }
+}
+
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct ParameterFeatures(Vec<Feature>);
@@ -483,10 +616,10 @@ impl Parse for ParameterIn {
         let style = input.parse::<Ident>()?;
 
         match &*style.to_string() {
-            "Path" => Ok(Self::Path),
-            "Query" => Ok(Self::Query),
-            "Header" => Ok(Self::Header),
-            "Cookie" => Ok(Self::Cookie),
+            "Path" | "path" => Ok(Self::Path),
+            "Query" | "query" => Ok(Self::Query),
+            "Header" | "header" => Ok(Self::Header),
+            "Cookie" | "cookie" => Ok(Self::Cookie),
             _ => Err(Error::new(style.span(), expected_style())),
         }
     }
@@ -561,3 +694,32 @@ impl ToTokens for ParameterStyle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::ValueParameter;
+
+    #[test]
+    fn parameter_example_not_matching_type_is_rejected() {
+        let error = syn::parse2::<ValueParameter>(quote!(("id" = u64, Path, example = "abc")))
+            .expect_err("string example for integer parameter should fail");
+
+        assert_eq!(
+            error.to_string(),
+            "example type does not match the parameter type, expected integer example for `u64`"
+        );
+    }
+
+    #[test]
+    fn parameter_example_matching_type_is_accepted() {
+        for parameter in [
+            quote!(("id" = u64, Path, example = 10)),
+            quote!(("name" = Option<String>, Query, example = "doggie")),
+            quote!(("price" = f64, Query, example = 10)),
+        ] {
+            assert!(syn::parse2::<ValueParameter>(parameter).is_ok());
+        }
+    }
+}
@@ -71,11 +71,6 @@ impl<'r> RequestBodyAttr<'r> {
         }
     }
 
-    #[cfg(any(
-        feature = "actix_extras",
-        feature = "rocket_extras",
-        feature = "axum_extras"
-    ))]
     pub fn from_schema(schema: Schema<'r>) -> RequestBodyAttr<'r> {
         Self {
             content: vec![MediaTypeAttr {
@@ -86,20 +81,30 @@ impl<'r> RequestBodyAttr<'r> {
         }
     }
 
+    /// Get content type of the request body, either defined or the default one, if request
+    /// body has only single content type.
+    pub fn get_content_type(&self) -> Result<Option<parse_utils::LitStrOrExpr>, Diagnostics> {
+        match self.content.as_slice() {
+            [media_type] => match media_type.content_type.as_ref() {
+                Some(content_type) => Ok(Some(content_type.clone())),
+                None => {
+                    let default_content_type = media_type.schema.get_default_content_type()?;
+                    Ok(Some(default_content_type.into_owned())
+                        .filter(|content_type| !content_type.is_empty())
+                        .map(Into::into))
+                }
+            },
+            _ => Ok(None),
+        }
+    }
+
     pub fn get_component_schemas(
         &self,
     ) -> Result<impl Iterator<Item = (bool, ComponentSchema)>, Diagnostics> {
         Ok(self
             .content
             .iter()
-            .map(
-                |media_type| match media_type.schema.get_component_schema() {
-                    Ok(component_schema) => {
-                        Ok(Some(media_type.schema.is_inline()).zip(component_schema))
-                    }
-                    Err(error) => Err(error),
-                },
-            )
+            .map(|media_type| media_type.schema.get_component_schemas())
             .collect::<Result<Vec<_>, Diagnostics>>()?
             .into_iter()
             .flatten())
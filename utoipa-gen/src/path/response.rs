@@ -49,6 +49,24 @@ impl Parse for Response<'_> {
 }
 
 impl Response<'_> {
+    /// Set `content_type` for media types of the response that do not define content type.
+    pub fn set_default_content_type(&mut self, content_type: &parse_utils::LitStrOrExpr) {
+        if let Self::Tuple(ResponseTuple {
+            inner: Some(ResponseTupleInner::Value(value)),
+            ..
+        }) = self
+        {
+            value
+                .content
+                .iter_mut()
+                .filter(|media_type| {
+                    !matches!(media_type.schema, Schema::Default(DefaultSchema::None))
+                        && media_type.content_type.is_none()
+                })
+                .for_each(|media_type| media_type.content_type = Some(content_type.clone()));
+        }
+    }
+
     pub fn get_component_schemas(
         &self,
     ) -> Result<impl Iterator<Item = (bool, ComponentSchema)>, Diagnostics> {
@@ -60,15 +78,7 @@ impl Response<'_> {
                         value
                             .content
                             .iter()
-                            .map(
-                                |media_type| match media_type.schema.get_component_schema() {
-                                    Ok(component_schema) => {
-                                        Ok(Some(media_type.schema.is_inline())
-                                            .zip(component_schema))
-                                    }
-                                    Err(error) => Err(error),
-                                },
-                            )
+                            .map(|media_type| media_type.schema.get_component_schemas())
                             .collect::<Result<Vec<_>, Diagnostics>>()?
                             .into_iter()
                             .flatten(),
@@ -113,7 +123,7 @@ pub struct ResponseTuple<'r> {
 }
 
 const RESPONSE_INCOMPATIBLE_ATTRIBUTES_MSG: &str =
-    "The `response` attribute may only be used in conjunction with the `status` attribute";
+    "The `response` and `ref_response` attributes may only be used in conjunction with the `status` attribute";
 
 impl<'r> ResponseTuple<'r> {
     /// Set as `ResponseValue` the content. This will fail if `response` attribute is already
@@ -131,6 +141,9 @@ impl<'r> ResponseTuple<'r> {
             Some(ResponseTupleInner::Ref(_)) => {
                 return Err(Error::new(ident.span(), format!("Cannot use `{attribute}` in conjunction with `response`. The `response` attribute can only be used in conjunction with `status` attribute.")));
             }
+            Some(ResponseTupleInner::RefName(_)) => {
+                return Err(Error::new(ident.span(), format!("Cannot use `{attribute}` in conjunction with `ref_response`. The `ref_response` attribute can only be used in conjunction with `status` attribute.")));
+            }
             None => {
                 let mut value = ResponseValue {
                     content: vec![MediaTypeAttr::default()],
@@ -149,7 +162,19 @@ impl<'r> ResponseTuple<'r> {
         match &mut self.inner {
             None => self.inner = Some(ResponseTupleInner::Ref(ty)),
             Some(ResponseTupleInner::Ref(r)) => *r = ty,
-            Some(ResponseTupleInner::Value(_)) => {
+            Some(ResponseTupleInner::Value(_) | ResponseTupleInner::RefName(_)) => {
+                return Err(Error::new(span, RESPONSE_INCOMPATIBLE_ATTRIBUTES_MSG))
+            }
+        }
+        Ok(())
+    }
+
+    // Use with the `ref_response` attribute, this will fail if an incompatible attribute has already been set
+    fn set_ref_name(&mut self, span: Span, name: parse_utils::LitStrOrExpr) -> syn::Result<()> {
+        match &mut self.inner {
+            None => self.inner = Some(ResponseTupleInner::RefName(name)),
+            Some(ResponseTupleInner::RefName(r)) => *r = name,
+            Some(ResponseTupleInner::Value(_) | ResponseTupleInner::Ref(_)) => {
                 return Err(Error::new(span, RESPONSE_INCOMPATIBLE_ATTRIBUTES_MSG))
             }
         }
@@ -161,12 +186,14 @@ impl<'r> ResponseTuple<'r> {
 enum ResponseTupleInner<'r> {
     Value(ResponseValue<'r>),
     Ref(ParsedType<'r>),
+    /// Name of the response defined in `components.responses`.
+    RefName(parse_utils::LitStrOrExpr),
 }
 
 impl Parse for ResponseTuple<'_> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTES: &str =
-            "status, description, body, content_type, headers, example, examples, response";
+            "status, description, body, content_type, headers, example, examples, response, ref_response";
 
         let mut response = ResponseTuple::default();
 
@@ -191,6 +218,12 @@ impl Parse for ResponseTuple<'_> {
                         parse_utils::parse_next(input, || input.parse())?,
                     )?;
                 }
+                "ref_response" => {
+                    response.set_ref_name(
+                        input.span(),
+                        parse_utils::parse_next_literal_str_or_expr(input)?,
+                    )?;
+                }
                 _ => {
                     response.set_as_value(&ident, name, |value| {
                         value.parse_named_attributes(input, &ident)
@@ -423,6 +456,11 @@ impl ToTokensDiagnostics for ResponseTuple<'_> {
                     });
                 }
             }
+            Some(ResponseTupleInner::RefName(name)) => {
+                tokens.extend(quote! {
+                    utoipa::openapi::Ref::from_response_name(#name)
+                });
+            }
             Some(ResponseTupleInner::Value(value)) => {
                 let description = &value.description;
                 tokens.extend(quote! {
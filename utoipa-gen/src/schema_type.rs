@@ -163,6 +163,10 @@ impl SchemaType<'_> {
         )
     }
 
+    pub fn is_char(&self) -> bool {
+        self.last_segment_to_string() == "char"
+    }
+
     pub fn is_number(&self) -> bool {
         match &*self.last_segment_to_string() {
             "f32" | "f64" => true,
@@ -251,6 +255,7 @@ impl ToTokensDiagnostics for SchemaType<'_> {
         }
 
         match name {
+            // `char` additionally gets `maxLength: 1` when resolved as a component schema
             "String" | "str" | "char" => {
                 schema_type_tokens(tokens, SchemaTypeInner::String, self.nullable)
             }
@@ -351,7 +356,7 @@ pub enum KnownFormat {
     RelativeJsonPointer,
     Regex,
     /// Custom format is reserved only for manual entry.
-    Custom(String),
+    Custom(LitStr),
     /// This is not tokenized, but is present for purpose of having some format in
     /// case we do not know the format. E.g. We cannot determine the format based on type path.
     #[allow(unused)]
@@ -368,6 +373,23 @@ impl KnownFormat {
         })?;
         let name = &*last_segment.ident.to_string();
 
+        // Rust integer types map to closest OpenAPI integer format as follows. Unsigned types
+        // additionally get `minimum: 0` unless explicit minimum is defined.
+        //
+        // | Rust type               | format  | format with `non_strict_integers` |
+        // |-------------------------|---------|-----------------------------------|
+        // | `i8`                    | `int32` | `int8`                            |
+        // | `i16`                   | `int32` | `int16`                           |
+        // | `u8`                    | `int32` | `uint8`                           |
+        // | `u16`                   | `int32` | `uint16`                          |
+        // | `u32`                   | `int32` | `uint32`                          |
+        // | `i32`                   | `int32` | `int32`                           |
+        // | `i64`, `isize`          | `int64` | `int64`                           |
+        // | `u64`, `usize`          | `int64` | `uint64`                          |
+        // | `i128`, `u128`          | -       | -                                 |
+        //
+        // The pointer width of the compilation target is not known to the macro thus `usize`
+        // and `isize` are always considered 64 bit wide.
         let variant = match name {
             #[cfg(feature = "non_strict_integers")]
             "i8" => Self::Int8,
@@ -380,16 +402,16 @@ impl KnownFormat {
             #[cfg(feature = "non_strict_integers")]
             "u32" => Self::UInt32,
             #[cfg(feature = "non_strict_integers")]
-            "u64" => Self::UInt64,
+            "u64" | "usize" => Self::UInt64,
 
             #[cfg(not(feature = "non_strict_integers"))]
             "i8" | "i16" | "u8" | "u16" | "u32" => Self::Int32,
 
             #[cfg(not(feature = "non_strict_integers"))]
-            "u64" => Self::Int64,
+            "u64" | "usize" => Self::Int64,
 
             "i32" => Self::Int32,
-            "i64" => Self::Int64,
+            "i64" | "isize" => Self::Int64,
             "f32" => Self::Float,
             "f64" => Self::Double,
 
@@ -427,6 +449,10 @@ impl KnownFormat {
     }
 
     fn get_allowed_formats() -> String {
+        Self::get_allowed_format_names().join(", ")
+    }
+
+    fn get_allowed_format_names() -> Vec<&'static str> {
         let default_formats = [
             "Int32",
             "Int64",
@@ -473,16 +499,70 @@ impl KnownFormat {
                 .chain(non_strict_integer_formats)
                 .collect::<Vec<_>>();
             formats.sort_unstable();
-            formats.join(", ")
+            formats.dedup();
+            formats
         };
         #[cfg(not(feature = "non_strict_integers"))]
-        let formats = {
-            let formats = default_formats.into_iter().collect::<Vec<_>>();
-            formats.join(", ")
-        };
+        let formats = default_formats.into_iter().collect::<Vec<_>>();
 
         formats
     }
+
+    /// Find known format which closely resembles the given custom format. This is used to hint
+    /// user about a possible typo in custom format e.g. _`datetime`_ instead of _`date-time`_.
+    ///
+    /// Custom format is considered similar to known format if it equals to the known format
+    /// ignoring case and separators, or if it is at most one edit away from it. Custom format that
+    /// is exactly the name of the known format in the OpenAPI spec is never considered a typo.
+    fn find_similar_known_format(custom: &str) -> Option<(&'static str, String)> {
+        fn normalize(value: &str) -> String {
+            value
+                .chars()
+                .filter(|c| !matches!(c, '-' | '_' | ' '))
+                .flat_map(char::to_lowercase)
+                .collect()
+        }
+
+        fn is_one_edit_away(left: &str, right: &str) -> bool {
+            let (left, right) = (left.as_bytes(), right.as_bytes());
+            let (shorter, longer) = if left.len() <= right.len() {
+                (left, right)
+            } else {
+                (right, left)
+            };
+            if longer.len() - shorter.len() > 1 {
+                return false;
+            }
+
+            let prefix = shorter
+                .iter()
+                .zip(longer)
+                .take_while(|(left, right)| left == right)
+                .count();
+            let suffix_start = if shorter.len() == longer.len() {
+                prefix + 1
+            } else {
+                prefix
+            };
+
+            shorter.get(suffix_start..).unwrap_or_default()
+                == longer.get(prefix + 1..).unwrap_or_default()
+        }
+
+        let normalized = normalize(custom);
+
+        Self::get_allowed_format_names()
+            .into_iter()
+            .map(|name| {
+                let spec_name = crate::component::serde::RenameRule::Kebab.rename_variant(name);
+                (name, spec_name)
+            })
+            .filter(|(_, spec_name)| spec_name != custom)
+            .find(|(name, _)| {
+                let known = normalize(name);
+                known == normalized || (known.len() >= 6 && is_one_edit_away(&known, &normalized))
+            })
+    }
 }
 
 impl Parse for KnownFormat {
@@ -545,8 +625,7 @@ impl Parse for KnownFormat {
                 )),
             }
         } else if lookahead.peek(LitStr) {
-            let value = input.parse::<LitStr>()?.value();
-            Ok(Self::Custom(value))
+            Ok(Self::Custom(input.parse::<LitStr>()?))
         } else {
             Err(lookahead.error())
         }
@@ -656,9 +735,28 @@ impl ToTokens for KnownFormat {
             Self::Regex => tokens.extend(quote!(utoipa::openapi::schema::SchemaFormat::KnownFormat(
                 utoipa::openapi::schema::KnownFormat::Regex
             ))),
-            Self::Custom(value) => tokens.extend(quote!(utoipa::openapi::schema::SchemaFormat::Custom(
-                String::from(#value)
-            ))),
+            Self::Custom(value) => {
+                let custom = quote!(utoipa::openapi::schema::SchemaFormat::Custom(
+                    String::from(#value)
+                ));
+
+                // Warn about likely typo in custom format.
+                if let Some((name, spec_name)) =
+                    KnownFormat::find_similar_known_format(&value.value())
+                {
+                    let note = format!(
+                        "custom format `{value}` is not a known format, did you mean `format = {name}` (`{spec_name}`)?",
+                        value = value.value()
+                    );
+                    let warning = crate::deprecation_warning(value.span(), &note);
+                    tokens.extend(quote! {{
+                        #warning
+                        #custom
+                    }})
+                } else {
+                    tokens.extend(custom)
+                }
+            }
             Self::Unknown => (), // unknown we just skip it
         };
     }
@@ -742,3 +840,42 @@ impl PrimitiveType {
         Some(Self { ty })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use quote::{quote, ToTokens};
+
+    use super::KnownFormat;
+
+    #[test]
+    fn custom_format_resembling_known_format_emits_deprecated_note() {
+        let format = syn::parse2::<KnownFormat>(quote!("datetime")).unwrap();
+
+        let tokens = format.to_token_stream().to_string();
+
+        assert!(
+            tokens.contains("deprecated"),
+            "expected deprecated warning in: {tokens}"
+        );
+        assert!(
+            tokens.contains(
+                "custom format `datetime` is not a known format, did you mean `format = DateTime` (`date-time`)?"
+            ),
+            "expected custom format note in: {tokens}"
+        );
+    }
+
+    #[test]
+    fn custom_format_not_resembling_known_format_emits_no_warning() {
+        for custom in ["date-time", "my-custom-format"] {
+            let format = syn::parse2::<KnownFormat>(quote!(#custom)).unwrap();
+
+            let tokens = format.to_token_stream().to_string();
+
+            assert!(
+                !tokens.contains("deprecated"),
+                "unexpected warning in: {tokens}"
+            );
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Compile the files in `testdata/warnings` with rustc and assert that the warnings emitted by
+//! the derive macros match the sibling `.stderr` files.
+//!
+//! Each `testdata/warnings/{name}.rs` is compiled as a binary of a scratch crate depending on
+//! `utoipa`. Only warnings pointing to the file itself are compared, in `--message-format=short`
+//! with the `src/bin/` prefix stripped.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+#[test]
+fn derive_emits_expected_warnings() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let testdata = manifest_dir.join("tests/testdata/warnings");
+    let krate = Path::new(env!("CARGO_TARGET_TMPDIR")).join("derive-warnings");
+
+    let _ = fs::remove_dir_all(krate.join("src"));
+    fs::create_dir_all(krate.join("src/bin")).unwrap();
+    fs::write(
+        krate.join("Cargo.toml"),
+        format!(
+            r#"[package]
+name = "derive-warnings"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[dependencies]
+utoipa = {{ path = {:?} }}
+
+[workspace]
+"#,
+            manifest_dir.join("../utoipa")
+        ),
+    )
+    .unwrap();
+    if let Ok(lock) = fs::read(manifest_dir.join("../Cargo.lock")) {
+        fs::write(krate.join("Cargo.lock"), lock).unwrap();
+    }
+
+    let mut fixtures = fs::read_dir(&testdata)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+        .collect::<Vec<PathBuf>>();
+    fixtures.sort();
+    assert!(!fixtures.is_empty(), "no fixtures in {testdata:?}");
+
+    for fixture in &fixtures {
+        fs::copy(
+            fixture,
+            krate.join("src/bin").join(fixture.file_name().unwrap()),
+        )
+        .unwrap();
+    }
+
+    let output = Command::new(env!("CARGO"))
+        .args(["check", "--quiet", "--bins", "--message-format=short"])
+        .arg("--manifest-path")
+        .arg(krate.join("Cargo.toml"))
+        .env("CARGO_TARGET_DIR", krate.join("target"))
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        output.status.success(),
+        "fixtures failed to compile:\n{stderr}"
+    );
+
+    for fixture in &fixtures {
+        let name = fixture.file_name().unwrap().to_str().unwrap();
+        let prefix = format!("{name}:");
+        let actual = stderr
+            .lines()
+            .filter_map(|line| line.strip_prefix("src/bin/"))
+            .filter(|line| line.starts_with(&prefix))
+            .map(|line| format!("{line}\n"))
+            .collect::<String>();
+        let expected = fs::read_to_string(fixture.with_extension("stderr")).unwrap();
+
+        assert_eq!(actual, expected, "unexpected warnings for {name}");
+    }
+}
@@ -1,7 +1,9 @@
 use utoipa::{
     openapi::{
         self,
-        security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+        security::{
+            AuthorizationCode, Flow, HttpAuthScheme, HttpBuilder, OAuth2, Scopes, SecurityScheme,
+        },
     },
     Modify, OpenApi,
 };
@@ -42,3 +44,100 @@ fn modify_openapi_add_security_scheme() {
         "components.securitySchemes.api_jwt_token.bearerFormat" = r###""JWT""###, "api_jwt_token bearerFormat"
     }
 }
+
+#[test]
+fn modify_openapi_add_oauth2_security_scheme() {
+    #[derive(Default, OpenApi)]
+    #[openapi(modifiers(&OAuth2Addon))]
+    struct ApiDoc;
+
+    struct OAuth2Addon;
+
+    impl Modify for OAuth2Addon {
+        fn modify(&self, openapi: &mut openapi::OpenApi) {
+            let components = openapi.components.get_or_insert_with(Default::default);
+            components.add_security_scheme(
+                "api_oauth2",
+                SecurityScheme::OAuth2(OAuth2::new([Flow::AuthorizationCode(
+                    AuthorizationCode::new(
+                        "https://localhost/authorize",
+                        "https://localhost/token",
+                        Scopes::from_iter([
+                            ("read:pets", "read pets"),
+                            ("write:pets", "write pets"),
+                        ]),
+                    ),
+                )])),
+            );
+        }
+    }
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "components.securitySchemes.api_oauth2.type" = r###""oauth2""###, "api_oauth2 type"
+        "components.securitySchemes.api_oauth2.flows.authorizationCode.authorizationUrl" = r###""https://localhost/authorize""###, "api_oauth2 authorizationUrl"
+        "components.securitySchemes.api_oauth2.flows.authorizationCode.tokenUrl" = r###""https://localhost/token""###, "api_oauth2 tokenUrl"
+        "components.securitySchemes.api_oauth2.flows.authorizationCode.scopes.read:pets" = r###""read pets""###, "api_oauth2 read:pets scope"
+        "components.securitySchemes.api_oauth2.flows.authorizationCode.scopes.write:pets" = r###""write pets""###, "api_oauth2 write:pets scope"
+    }
+}
+
+#[test]
+fn modify_openapi_add_response_to_every_operation_in_order() {
+    #[utoipa::path(get, path = "/pets", responses((status = 200, description = "Pets")))]
+    #[allow(unused)]
+    fn get_pets() {}
+
+    #[utoipa::path(post, path = "/pets", responses((status = 201, description = "Created")))]
+    #[allow(unused)]
+    fn create_pet() {}
+
+    #[derive(Default, OpenApi)]
+    #[openapi(
+        paths(get_pets, create_pet),
+        modifiers(&ErrorResponseAddon, &ErrorDescriptionAddon)
+    )]
+    struct ApiDoc;
+
+    struct ErrorResponseAddon;
+
+    impl Modify for ErrorResponseAddon {
+        fn modify(&self, openapi: &mut openapi::OpenApi) {
+            openapi.for_each_operation(|_, _, operation| {
+                operation.responses.responses.insert(
+                    "500".to_string(),
+                    openapi::Response::new("Internal server error").into(),
+                );
+            });
+        }
+    }
+
+    struct ErrorDescriptionAddon;
+
+    impl Modify for ErrorDescriptionAddon {
+        fn modify(&self, openapi: &mut openapi::OpenApi) {
+            openapi.for_each_operation(|path, method, operation| {
+                if let Some(openapi::RefOr::T(response)) =
+                    operation.responses.responses.get_mut("500")
+                {
+                    let method = match method {
+                        openapi::HttpMethod::Get => "GET",
+                        openapi::HttpMethod::Post => "POST",
+                        _ => "OTHER",
+                    };
+                    response.description = format!("{method} {path} failed");
+                }
+            });
+        }
+    }
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "paths.~1pets.get.responses.200.description" = r###""Pets""###, "get 200 description"
+        "paths.~1pets.get.responses.500.description" = r###""GET /pets failed""###, "get 500 description"
+        "paths.~1pets.post.responses.201.description" = r###""Created""###, "post 201 description"
+        "paths.~1pets.post.responses.500.description" = r###""POST /pets failed""###, "post 500 description"
+    }
+}
@@ -391,6 +391,135 @@ fn derive_nest_openapi_with_tags() {
     assert_json_snapshot!(paths);
 }
 
+#[test]
+fn derive_openapi_with_path_prefix() {
+    #[utoipa::path(get, path = "/pets")]
+    #[allow(dead_code)]
+    fn get_pets() {}
+
+    #[utoipa::path(get, path = "status")]
+    #[allow(dead_code)]
+    fn get_status() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_pets, get_status), path_prefix = "/api/v1/")]
+    struct ApiDoc;
+
+    let api = ApiDoc::openapi();
+    let paths = api.paths.paths.keys().collect::<Vec<_>>();
+
+    assert_eq!(paths, ["/api/v1/pets", "/api/v1/status"]);
+}
+
+#[test]
+fn derive_openapi_with_info_summary_and_operation_overrides() {
+    /// Summary from doc comment
+    ///
+    /// Description from doc comment
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        summary = "List pets",
+        description = "List all pets of the store"
+    )]
+    #[allow(dead_code)]
+    fn get_pets() {}
+
+    #[derive(OpenApi)]
+    #[openapi(
+        info(
+            title = "Pet store",
+            summary = "Pets of the store",
+            description = "Pet store API"
+        ),
+        paths(get_pets)
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).expect("OpenApi is JSON serializable");
+
+    assert_eq!(
+        doc.pointer("/info/summary"),
+        Some(&serde_json::json!("Pets of the store"))
+    );
+    assert_eq!(
+        doc.pointer("/info/description"),
+        Some(&serde_json::json!("Pet store API"))
+    );
+    assert_eq!(
+        doc.pointer("/paths/~1pets/get/summary"),
+        Some(&serde_json::json!("List pets"))
+    );
+    assert_eq!(
+        doc.pointer("/paths/~1pets/get/description"),
+        Some(&serde_json::json!("List all pets of the store"))
+    );
+}
+
+#[test]
+fn derive_openapi_with_nullable_field_uses_openapi_31_type_array() {
+    #[derive(ToSchema)]
+    #[allow(dead_code)]
+    struct Pet {
+        nickname: Option<String>,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Pet)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_eq!(doc.pointer("/openapi"), Some(&serde_json::json!("3.1.0")));
+    assert_eq!(
+        doc.pointer("/components/schemas/Pet/properties/nickname"),
+        Some(&serde_json::json!({"type": ["string", "null"]}))
+    );
+}
+
+mod models {
+    #[derive(utoipa::ToSchema)]
+    #[allow(dead_code)]
+    pub struct Pet {
+        name: String,
+    }
+
+    pub mod dto {
+        #[derive(utoipa::ToSchema)]
+        #[allow(dead_code)]
+        pub struct Order {
+            pet: super::Pet,
+        }
+    }
+
+    pub mod handlers {
+        #[utoipa::path(get, path = "/pets", responses((status = 200, body = super::Pet)))]
+        #[allow(dead_code)]
+        pub fn get_pet() {}
+    }
+}
+
+#[test]
+fn derive_openapi_with_qualified_paths_to_other_modules() {
+    mod api {
+        #[derive(utoipa::OpenApi)]
+        #[openapi(
+            paths(crate::models::handlers::get_pet),
+            components(schemas(crate::models::Pet, super::models::dto::Order))
+        )]
+        pub struct ApiDoc;
+    }
+
+    let value = serde_json::to_value(<api::ApiDoc as OpenApi>::openapi()).unwrap();
+
+    assert!(value.pointer("/paths/~1pets/get").is_some());
+    assert!(value.pointer("/components/schemas/Pet").is_some());
+    assert_eq!(
+        value.pointer("/components/schemas/Order/properties/pet/$ref"),
+        Some(&serde_json::json!("#/components/schemas/Pet"))
+    );
+}
+
 #[test]
 fn openapi_schemas_resolve_generic_enum_schema() {
     #![allow(dead_code)]
@@ -576,3 +705,121 @@ fn derive_generic_openapi_component_schemas() {
 
     assert_json_snapshot!(schemas)
 }
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "schema name `Error` is registered by multiple different schemas")]
+fn derive_openapi_with_colliding_schema_names_panics() {
+    mod user {
+        #[derive(utoipa::ToSchema)]
+        #[allow(unused)]
+        pub struct Error {
+            pub reason: String,
+        }
+    }
+
+    mod order {
+        #[derive(utoipa::ToSchema)]
+        #[allow(unused)]
+        pub struct Error {
+            pub code: i32,
+        }
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(user::Error, order::Error)))]
+    struct ApiDoc;
+
+    ApiDoc::openapi();
+}
+
+#[test]
+fn derive_openapi_with_same_schema_registered_twice() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        pet: Pet,
+    }
+
+    #[derive(OpenApi)]
+    #[allow(clippy::duplicated_attributes)]
+    #[openapi(components(schemas(Pet, Owner, Pet)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).expect("OpenApi is JSON serializable");
+
+    assert_eq!(
+        doc.pointer("/components/schemas/Pet/properties/name/type"),
+        Some(&serde_json::json!("string"))
+    );
+}
+
+#[test]
+fn derive_openapi_serializes_deterministically() {
+    use utoipa::openapi::extensions::Extensions;
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        id: u64,
+        name: String,
+        tags: Vec<String>,
+    }
+
+    #[utoipa::path(get, path = "/pets", responses((status = 200, body = [Pet])))]
+    #[allow(dead_code)]
+    fn list_pets() {}
+
+    #[utoipa::path(get, path = "/pets/{id}", params(("id" = u64, Path)), responses((status = 200, body = Pet)))]
+    #[allow(dead_code)]
+    fn get_pet() {}
+
+    struct AddExtensions;
+
+    impl utoipa::Modify for AddExtensions {
+        fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+            openapi.extensions = Some(Extensions::from_iter(
+                ('a'..='z').map(|c| (format!("x-{c}"), c.to_string())),
+            ));
+        }
+    }
+
+    struct AddExtensionsReversed;
+
+    impl utoipa::Modify for AddExtensionsReversed {
+        fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+            openapi.extensions = Some(Extensions::from_iter(
+                ('a'..='z').rev().map(|c| (format!("x-{c}"), c.to_string())),
+            ));
+        }
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(paths(list_pets, get_pet), modifiers(&AddExtensions))]
+    struct ApiDoc;
+
+    #[derive(OpenApi)]
+    #[openapi(paths(list_pets, get_pet), modifiers(&AddExtensionsReversed))]
+    struct ApiDocReversed;
+
+    let json = ApiDoc::openapi().to_json().unwrap();
+
+    assert_eq!(json, ApiDocReversed::openapi().to_json().unwrap());
+
+    let positions = ('a'..='z')
+        .map(|c| {
+            json.find(&format!("\"x-{c}\""))
+                .expect("extension is serialized")
+        })
+        .collect::<Vec<_>>();
+    assert!(
+        positions.windows(2).all(|pair| pair[0] < pair[1]),
+        "extensions should be serialized in sorted key order: {json}"
+    );
+}
@@ -1247,6 +1247,49 @@ fn derive_path_with_tag_constant() {
     assert_json_snapshot!(&operation);
 }
 
+#[test]
+fn derive_path_with_explicit_tag_overrides_module_tag() {
+    mod pet_handlers {
+        #[utoipa::path(
+            get,
+            tag = "pets",
+            path = "/pets",
+            responses(
+                (status = 200, description = "success response")
+            ),
+        )]
+        #[allow(unused)]
+        pub fn get_pets() {}
+
+        #[utoipa::path(
+            get,
+            path = "/owners",
+            responses(
+                (status = 200, description = "success response")
+            ),
+        )]
+        #[allow(unused)]
+        pub fn get_owners() {}
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(paths(pet_handlers::get_pets, pet_handlers::get_owners))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_eq!(
+        doc.pointer("/paths/~1pets/get/tags"),
+        Some(&json!(["pets"])),
+        "explicit tag must win over the module tag"
+    );
+    assert_eq!(
+        doc.pointer("/paths/~1owners/get/tags"),
+        Some(&json!(["pet_handlers"])),
+        "module tag must be used when tag is not defined"
+    );
+}
+
 #[test]
 fn derive_path_with_multiple_tags() {
     #[allow(dead_code)]
@@ -1310,6 +1353,58 @@ split to multiple lines";
     assert_json_snapshot!(&operation);
 }
 
+#[test]
+fn derive_path_with_single_line_doc_comment_summary() {
+    /// Get all pets
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, description = "success response")
+        ),
+    )]
+    #[allow(unused)]
+    fn get_pets() {}
+
+    let operation = test_api_fn_doc! {
+        get_pets,
+        operation: get,
+        path: "/pets"
+    };
+
+    assert_value! {operation=>
+        "summary" = r#""Get all pets""#, "Api operation summary"
+        "description" = r#"null"#, "Api operation description"
+    }
+}
+
+#[test]
+fn derive_path_with_doc_attribute_summary_and_description() {
+    #[doc = "Get all pets"]
+    #[doc = ""]
+    #[doc = "Lists every pet in the store."]
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, description = "success response")
+        ),
+    )]
+    #[allow(unused)]
+    fn get_pets() {}
+
+    let operation = test_api_fn_doc! {
+        get_pets,
+        operation: get,
+        path: "/pets"
+    };
+
+    assert_value! {operation=>
+        "summary" = r#""Get all pets""#, "Api operation summary"
+        "description" = r#""Lists every pet in the store.""#, "Api operation description"
+    }
+}
+
 #[test]
 fn derive_path_include_str_description() {
     #[allow(dead_code)]
@@ -1520,6 +1615,83 @@ fn derive_path_with_response_links() {
     assert_json_snapshot!(&paths);
 }
 
+#[test]
+fn derive_path_with_deprecated_note_in_description() {
+    #![allow(dead_code, deprecated)]
+
+    /// Get pet
+    ///
+    /// Get pet by its id.
+    #[utoipa::path(get, path = "/pets/{id}", responses((status = 200, description = "Pet")))]
+    #[deprecated(note = "Use /v2/pets/{id} instead")]
+    async fn get_pet() {}
+
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        description = "List pets",
+        responses((status = 200, description = "Pets"))
+    )]
+    #[deprecated = "Use /v2/pets instead"]
+    async fn list_pets() {}
+
+    #[utoipa::path(delete, path = "/pets/{id}", responses((status = 204, description = "Deleted")))]
+    #[deprecated(since = "1.0.0", note = "Pets are never deleted")]
+    async fn delete_pet() {}
+
+    let description = |operation: utoipa::openapi::path::Operation| {
+        assert_eq!(
+            operation.deprecated,
+            Some(utoipa::openapi::Deprecated::True)
+        );
+        operation.description.expect("must have description")
+    };
+
+    assert_eq!(
+        description(__path_get_pet::operation()),
+        "Get pet by its id.\n\nDeprecated: Use /v2/pets/{id} instead"
+    );
+    assert_eq!(
+        description(__path_list_pets::operation()),
+        "List pets\n\nDeprecated: Use /v2/pets instead"
+    );
+    assert_eq!(
+        description(__path_delete_pet::operation()),
+        "Deprecated: Pets are never deleted"
+    );
+}
+
+#[test]
+fn derive_path_with_bodyless_responses_omits_content() {
+    #![allow(dead_code)]
+
+    #[utoipa::path(
+        put,
+        path = "/pets/{id}",
+        request_body(content = String, content_type = "text/plain"),
+        mirror_request_content_type,
+        responses(
+            (status = 204, description = "No content"),
+            (status = 304, description = "Not modified"),
+        ),
+    )]
+    async fn update_pet(_body: String) {}
+
+    let operation = serde_json::to_value(__path_update_pet::operation())
+        .expect("Operation is JSON serializable");
+    let responses = operation
+        .pointer("/responses")
+        .expect("must have responses");
+
+    assert_eq!(
+        responses,
+        &json!({
+            "204": { "description": "No content" },
+            "304": { "description": "Not modified" }
+        })
+    );
+}
+
 #[test]
 fn derive_path_test_collect_request_body() {
     #![allow(dead_code)]
@@ -1723,6 +1895,83 @@ fn derive_path_test_collect_generic_request_body() {
     assert_json_snapshot!(&schemas);
 }
 
+#[test]
+fn derive_path_test_collect_enum_path_parameter() {
+    #![allow(dead_code)]
+
+    #[derive(ToSchema)]
+    enum PetKind {
+        Dog,
+        Cat,
+    }
+
+    #[derive(ToSchema)]
+    enum Size {
+        Small,
+        Large,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets/{kind}/{size}",
+        params(
+            ("kind" = PetKind, Path, description = "Kind of the pet"),
+            ("size" = inline(Size), Path, description = "Size of the pet"),
+        ),
+        responses(
+            (status = 200, description = "success response")
+        ),
+    )]
+    async fn get_pets() {}
+
+    use utoipa::OpenApi;
+    #[derive(OpenApi)]
+    #[openapi(paths(get_pets))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc
+        .pointer("/paths/~1pets~1{kind}~1{size}/get/parameters")
+        .expect("OpenApi must have parameters");
+    let schemas = doc
+        .pointer("/components/schemas")
+        .expect("OpenApi must have schemas");
+
+    assert_eq!(
+        parameters,
+        &json!([
+            {
+                "name": "kind",
+                "in": "path",
+                "description": "Kind of the pet",
+                "required": true,
+                "schema": {
+                    "$ref": "#/components/schemas/PetKind"
+                }
+            },
+            {
+                "name": "size",
+                "in": "path",
+                "description": "Size of the pet",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                    "enum": ["Small", "Large"]
+                }
+            }
+        ])
+    );
+    assert_eq!(
+        schemas,
+        &json!({
+            "PetKind": {
+                "type": "string",
+                "enum": ["Dog", "Cat"]
+            }
+        })
+    );
+}
+
 #[test]
 fn path_derive_with_body_ref_using_as_attribute_schema() {
     #![allow(unused)]
@@ -1882,6 +2131,58 @@ fn derive_multipart_form_data() {
     assert_json_snapshot!(&request_body);
 }
 
+#[test]
+fn derive_multipart_form_data_with_binary_file() {
+    #![allow(dead_code)]
+
+    #[derive(utoipa::ToSchema)]
+    struct UploadForm {
+        name: String,
+        #[schema(format = Binary, content_media_type = "application/octet-stream")]
+        file: Vec<u8>,
+        #[schema(format = Binary)]
+        thumbnail: Option<Vec<u8>>,
+    }
+
+    #[utoipa::path(
+        post,
+        request_body(content = inline(UploadForm), content_type = "multipart/form-data"),
+        path = "/upload",
+        responses(
+            (status = 200, description = "success response")
+        ),
+    )]
+    async fn upload(_body: UploadForm) {}
+
+    let operation =
+        serde_json::to_value(__path_upload::operation()).expect("Operation is JSON serializable");
+    let schema = operation
+        .pointer("/requestBody/content/multipart~1form-data/schema")
+        .expect("must have multipart request body");
+
+    assert_eq!(
+        schema,
+        &json!({
+            "type": "object",
+            "required": ["name", "file"],
+            "properties": {
+                "name": {
+                    "type": "string"
+                },
+                "file": {
+                    "type": "string",
+                    "format": "binary",
+                    "contentMediaType": "application/octet-stream"
+                },
+                "thumbnail": {
+                    "type": ["string", "null"],
+                    "format": "binary"
+                }
+            }
+        })
+    );
+}
+
 #[test]
 fn derive_images_as_application_octet_stream() {
     #![allow(dead_code)]
@@ -1959,3 +2260,41 @@ fn derive_lifetime_generic_request_body_compiles() {
     let _ = serde_json::to_value(__path_test_const_generic::operation())
         .expect("Operation is JSON serializable");
 }
+
+#[test]
+fn derive_path_with_external_docs() {
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        external_docs(url = "https://example.com/pets", description = "More about pets"),
+        responses(
+            (status = 200, description = "success response")
+        ),
+    )]
+    #[allow(unused)]
+    fn get_pets() {}
+
+    #[derive(OpenApi)]
+    #[openapi(
+        paths(get_pets),
+        external_docs(url = "https://example.com", description = "More about the API")
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_eq!(
+        doc.pointer("/paths/~1pets/get/externalDocs"),
+        Some(&json!({
+            "url": "https://example.com/pets",
+            "description": "More about pets"
+        }))
+    );
+    assert_eq!(
+        doc.pointer("/externalDocs"),
+        Some(&json!({
+            "url": "https://example.com",
+            "description": "More about the API"
+        }))
+    );
+}
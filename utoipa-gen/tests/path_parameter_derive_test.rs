@@ -293,6 +293,147 @@ fn derive_params_with_params_ext() {
     };
 }
 
+#[test]
+fn derive_params_with_literal_examples_matching_type() {
+    #[utoipa::path(
+        get,
+        path = "/foo/{id}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("id" = i64, Path, example = -1),
+            ("ratio" = Option<f32>, Query, example = 1),
+            ("name" = String, Query, example = "doggie"),
+            ("active" = bool, Query, example = true),
+        )
+    )]
+    #[allow(unused)]
+    async fn get_foo(id: i64) -> String {
+        "".to_string()
+    }
+
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc.pointer("/paths/~1foo~1{id}/get/parameters").unwrap();
+
+    assert_value! {parameters=>
+        "[0].example" = r#"-1"#, "Parameter example"
+        "[1].example" = r#"1"#, "Parameter example"
+        "[2].example" = r#""doggie""#, "Parameter example"
+        "[3].example" = r#"true"#, "Parameter example"
+    };
+}
+
+#[test]
+fn derive_params_matching_path_placeholders() {
+    #[utoipa::path(
+        get,
+        path = "/owners/{owner_id}/pets/{id}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("owner_id" = u64, Path, description = "Owner id"),
+            ("id" = u64, Path, description = "Pet id"),
+            ("name" = Option<String>, Query, description = "Pet name"),
+        )
+    )]
+    #[allow(unused)]
+    async fn get_pet(owner_id: u64, id: u64) -> String {
+        "".to_string()
+    }
+
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(get_pet))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc
+        .pointer("/paths/~1owners~1{owner_id}~1pets~1{id}/get/parameters")
+        .unwrap();
+
+    common::assert_json_array_len(parameters, 3);
+    assert_value! {parameters=>
+        "[0].name" = r#""owner_id""#, "Parameter name"
+        "[0].in" = r#""path""#, "Parameter in"
+        "[1].name" = r#""id""#, "Parameter name"
+        "[1].in" = r#""path""#, "Parameter in"
+    };
+}
+
+#[test]
+fn derive_params_matching_context_path_placeholders() {
+    #[utoipa::path(
+        get,
+        context_path = "/tenants/{tenant}",
+        path = "/pets/{id}",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("tenant" = String, Path, description = "Tenant"),
+            ("id" = u64, Path, description = "Pet id"),
+        )
+    )]
+    #[allow(unused)]
+    async fn get_tenant_pet(tenant: String, id: u64) -> String {
+        "".to_string()
+    }
+
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(get_tenant_pet))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc
+        .pointer("/paths/~1tenants~1{tenant}~1pets~1{id}/get/parameters")
+        .unwrap();
+
+    common::assert_json_array_len(parameters, 2);
+    assert_value! {parameters=>
+        "[0].name" = r#""tenant""#, "Parameter name"
+        "[1].name" = r#""id""#, "Parameter name"
+    };
+}
+
+#[test]
+fn derive_params_with_lowercase_parameter_in() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("session" = String, cookie, description = "Session id"),
+            ("x-request-id" = String, header, description = "Request id"),
+        )
+    )]
+    #[allow(unused)]
+    async fn get_foo() -> String {
+        "".to_string()
+    }
+
+    #[derive(OpenApi, Default)]
+    #[openapi(paths(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = doc.pointer("/paths/~1foo/get/parameters").unwrap();
+
+    common::assert_json_array_len(parameters, 2);
+    assert_value! {parameters=>
+        "[0].in" = r#""cookie""#, "Parameter in"
+        "[0].name" = r#""session""#, "Parameter name"
+        "[1].in" = r#""header""#, "Parameter in"
+        "[1].name" = r#""x-request-id""#, "Parameter name"
+    };
+}
+
 #[test]
 fn derive_path_params_with_parameter_type_args() {
     #[utoipa::path(
@@ -340,6 +481,55 @@ macro_rules! into_params {
     };
 }
 
+#[test]
+fn derive_into_params_with_deprecated_field() {
+    let value = into_params! {
+        #[into_params(parameter_in = Query)]
+        #[allow(unused)]
+        struct PetFilter {
+            /// Name of the pet.
+            #[param(deprecated, example = "doggie")]
+            name: Option<String>,
+            /// Maximum number of results to return.
+            #[param(example = 12)]
+            limit: u32,
+        }
+    };
+
+    assert_value! {value=>
+        "[0].name" = r#""name""#, "Parameter name"
+        "[0].deprecated" = r#"true"#, "Parameter deprecated"
+        "[0].description" = r#""Name of the pet.""#, "Parameter description"
+        "[0].example" = r#""doggie""#, "Parameter example"
+        "[1].name" = r#""limit""#, "Parameter name"
+        "[1].deprecated" = r#"null"#, "Parameter deprecated"
+        "[1].description" = r#""Maximum number of results to return.""#, "Parameter description"
+        "[1].example" = r#"12"#, "Parameter example"
+    }
+}
+
+#[test]
+fn derive_into_params_with_deprecated_note_in_description() {
+    let value = into_params! {
+        #[into_params(parameter_in = Query)]
+        #[allow(unused)]
+        struct PetFilter {
+            /// Name of the pet.
+            #[deprecated(note = "Use name_filter instead")]
+            name: Option<String>,
+            #[deprecated = "Limit is ignored"]
+            limit: u32,
+        }
+    };
+
+    assert_value! {value=>
+        "[0].deprecated" = r#"true"#, "Parameter deprecated"
+        "[0].description" = r#""Name of the pet.\n\nDeprecated: Use name_filter instead""#, "Parameter description"
+        "[1].deprecated" = r#"true"#, "Parameter deprecated"
+        "[1].description" = r#""Deprecated: Limit is ignored""#, "Parameter description"
+    }
+}
+
 #[test]
 fn derive_into_params_required_custom_query_parameter_required() {
     #[allow(unused)]
@@ -135,6 +135,28 @@ fn derive_path_with_reusable_responses() {
     }
 }
 
+const NOT_FOUND: &str = "NotFound";
+
+test_fn! {
+    module: ref_responses,
+    responses: (
+        (status = 200, description = "success"),
+        (status = 404, ref_response = crate::NOT_FOUND),
+        (status = 500, ref_response = "InternalError")
+    )
+}
+
+#[test]
+fn derive_path_with_ref_responses() {
+    let doc = api_doc!(module: ref_responses);
+
+    assert_value! {doc=>
+        "responses.200.description" = r#""success""#, "Response description"
+        "responses.404.$ref" = "\"#/components/responses/NotFound\"", "Response reference"
+        "responses.500.$ref" = "\"#/components/responses/InternalError\"", "Response reference"
+    }
+}
+
 macro_rules! test_response_types {
     ( $( $name:ident=> $(body: $expected:expr,)? $( $content_type:literal, )? $( headers: $headers:expr, )?
         assert: $( $path:literal = $expectation:literal, $comment:literal )* )* ) => {
@@ -258,6 +280,34 @@ fn derive_response_with_json_example_success() {
     }
 }
 
+#[test]
+fn derive_response_with_example_on_schema_and_media_type() {
+    #[allow(unused)]
+    mod response_with_example_locations {
+        #[derive(utoipa::ToSchema)]
+        #[schema(example = json!({"name": "schema"}))]
+        struct Foo {
+            name: String,
+        }
+
+        #[utoipa::path(
+            get,
+            path = "/foo",
+            responses(
+                (status = 200, body = inline(Foo), example = json!({"name": "media type"}))
+            )
+        )]
+        fn get_foo() {}
+    }
+
+    let doc = api_doc!(module: response_with_example_locations);
+
+    assert_value! {doc=>
+        "responses.200.content.application~1json.schema.example" = r###"{"name":"schema"}"###, "Schema example"
+        "responses.200.content.application~1json.example" = r###"{"name":"media type"}"###, "Media type example"
+    }
+}
+
 #[test]
 fn derive_response_body_inline_schema_component() {
     test_fn! {
@@ -473,3 +523,95 @@ fn path_response_with_no_schema() {
     let value = serde_json::to_value(operation).expect("operation is JSON serializable");
     assert_json_snapshot!(value);
 }
+
+#[test]
+fn path_response_mirrors_request_content_type() {
+    #![allow(unused)]
+
+    #[derive(utoipa::ToSchema)]
+    struct Pet {
+        name: String,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/pets",
+        mirror_request_content_type,
+        request_body(content = Pet, content_type = "application/x-www-form-urlencoded"),
+        responses(
+            (status = 201, body = Pet),
+            (status = 400, body = String, content_type = "text/plain"),
+            (status = 204)
+        )
+    )]
+    fn create_pet() {}
+
+    let operation = __path_create_pet::operation();
+    let value = serde_json::to_value(operation).expect("operation is JSON serializable");
+    let content_types = |status: &str| {
+        value
+            .pointer(&format!("/responses/{status}/content"))
+            .and_then(Value::as_object)
+            .map(|content| content.keys().cloned().collect::<Vec<_>>())
+    };
+
+    assert_eq!(
+        content_types("201"),
+        Some(vec!["application/x-www-form-urlencoded".to_string()])
+    );
+    assert_eq!(content_types("400"), Some(vec!["text/plain".to_string()]));
+    assert_eq!(content_types("204"), None);
+}
+
+#[test]
+fn path_response_with_inline_object_body() {
+    #![allow(unused)]
+
+    #[derive(utoipa::ToSchema)]
+    struct User {
+        name: String,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/login",
+        responses(
+            (status = 200, body = object(token = String, expires_in = Option<u64>, "user" = User))
+        )
+    )]
+    fn login() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(login))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).expect("OpenApi is JSON serializable");
+    let schema = doc
+        .pointer("/paths/~1login/post/responses/200/content/application~1json/schema")
+        .expect("response should have application/json schema");
+
+    assert_eq!(
+        schema,
+        &serde_json::json!({
+            "type": "object",
+            "properties": {
+                "token": {
+                    "type": "string"
+                },
+                "expires_in": {
+                    "type": ["integer", "null"],
+                    "format": "int64",
+                    "minimum": 0
+                },
+                "user": {
+                    "$ref": "#/components/schemas/User"
+                }
+            },
+            "required": ["token", "user"]
+        })
+    );
+    assert!(
+        doc.pointer("/components/schemas/User").is_some(),
+        "User referenced from inline object should be collected"
+    );
+}
@@ -575,3 +575,47 @@ fn request_body_with_external_ref() {
         .unwrap();
     assert_json_snapshot!(content);
 }
+
+#[test]
+fn derive_request_body_from_handler_argument() {
+    #![allow(unused)]
+
+    #[derive(utoipa::ToSchema)]
+    pub struct Pet {
+        name: String,
+    }
+
+    pub struct Json<T>(T);
+
+    #[utoipa::path(
+        post,
+        path = "/pets/{id}",
+        request_body_from_arg,
+        params(("id" = i32, Path, description = "Pet id")),
+        responses(
+            (status = 200, description = "success response")
+        )
+    )]
+    fn update_pet(id: i32, pet: Json<Pet>) {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(update_pet))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let request_body = doc.pointer("/paths/~1pets~1{id}/post/requestBody").unwrap();
+
+    assert_eq!(
+        request_body,
+        &serde_json::json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "$ref": "#/components/schemas/Pet"
+                    }
+                }
+            },
+            "required": true
+        })
+    );
+}
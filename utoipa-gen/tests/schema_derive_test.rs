@@ -208,6 +208,161 @@ fn derive_struct_with_custom_properties_success() {
     };
 }
 
+#[test]
+fn derive_struct_with_bool_and_char() {
+    let flags = api_doc! {
+        struct Flags {
+            enabled: bool,
+            initial: char,
+            #[schema(max_length = 4)]
+            symbol: char,
+        }
+    };
+
+    assert_value! {flags=>
+        "properties.enabled.type" = r#""boolean""#, "bool type"
+        "properties.initial.type" = r#""string""#, "char type"
+        "properties.initial.maxLength" = r#"1"#, "char max length"
+        "properties.symbol.maxLength" = r#"4"#, "char explicit max length"
+    };
+}
+
+#[test]
+fn derive_struct_with_integer_key_map() {
+    let index = api_doc! {
+        struct Index {
+            names: std::collections::BTreeMap<u32, String>,
+            offsets: std::collections::BTreeMap<i64, u8>,
+            ranges: std::collections::BTreeMap<(u32, u32), String>,
+        }
+    };
+
+    assert_value! {index=>
+        "properties.names.type" = r#""object""#, "BTreeMap<u32, String> type"
+        "properties.names.additionalProperties.type" = r#""string""#, "BTreeMap<u32, String> value type"
+        "properties.names.propertyNames.type" = r#""string""#, "BTreeMap<u32, String> key type"
+        "properties.names.propertyNames.pattern" = r#""^[0-9]+$""#, "BTreeMap<u32, String> key pattern"
+        "properties.offsets.propertyNames.pattern" = r#""^-?[0-9]+$""#, "BTreeMap<i64, u8> key pattern"
+        "properties.ranges.type" = r#""object""#, "BTreeMap<(u32, u32), String> type"
+        "properties.ranges.additionalProperties.type" = r#""string""#, "BTreeMap<(u32, u32), String> value type"
+        "properties.ranges.propertyNames" = r#"null"#, "BTreeMap<(u32, u32), String> key type"
+    };
+}
+
+#[test]
+fn derive_struct_with_read_only_write_only_and_deprecated_field() {
+    use utoipa::openapi::schema::Schema;
+    use utoipa::openapi::RefOr;
+    use utoipa::PartialSchema;
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        name: String,
+    }
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Account {
+        #[schema(read_only, write_only, deprecated)]
+        secret: String,
+        #[schema(read_only, write_only, deprecated)]
+        owner: Owner,
+    }
+
+    let RefOr::T(Schema::Object(account)) = Account::schema() else {
+        panic!("Account should be an object schema");
+    };
+    let property = |name: &str| serde_json::to_string(&account.properties[name]).unwrap();
+
+    assert_eq!(
+        property("secret"),
+        r#"{"type":"string","deprecated":true,"writeOnly":true,"readOnly":true}"#
+    );
+    assert_eq!(
+        property("owner"),
+        r##"{"oneOf":[{"$ref":"#/components/schemas/Owner"}],"deprecated":true,"writeOnly":true,"readOnly":true}"##
+    );
+}
+
+#[test]
+fn derive_struct_with_const_value_fields() {
+    let event = api_doc! {
+        struct Event {
+            #[schema(const_value = true)]
+            deleted: bool,
+            #[schema(const_value = false)]
+            archived: Option<bool>,
+            #[schema(const_value = "pet.deleted")]
+            kind: String,
+        }
+    };
+
+    assert_value! {event=>
+        "properties.deleted.type" = r#""boolean""#, "Event deleted type"
+        "properties.deleted.enum" = r#"[true]"#, "Event deleted const value"
+        "properties.archived.type" = r#"["boolean","null"]"#, "Event archived type"
+        "properties.archived.enum" = r#"[false,null]"#, "Event archived const value"
+        "properties.kind.enum" = r#"["pet.deleted"]"#, "Event kind const value"
+    };
+}
+
+#[test]
+fn derive_struct_with_float_widths() {
+    let measurement = api_doc! {
+        struct Measurement {
+            ratio: f32,
+            value: f64,
+            previous: Option<f64>,
+        }
+    };
+
+    assert_value! {measurement=>
+        "properties.ratio.type" = r#""number""#, "f32 type"
+        "properties.ratio.format" = r#""float""#, "f32 format"
+        "properties.value.type" = r#""number""#, "f64 type"
+        "properties.value.format" = r#""double""#, "f64 format"
+        "properties.previous.type" = r#"["number","null"]"#, "Option<f64> type"
+        "properties.previous.format" = r#""double""#, "Option<f64> format"
+        "required" = r#"["ratio","value"]"#, "Measurement required fields"
+    };
+}
+
+#[cfg(not(feature = "non_strict_integers"))]
+#[test]
+fn derive_struct_with_integer_widths() {
+    let sizes = api_doc! {
+        struct Sizes {
+            tiny: i8,
+            small: u16,
+            medium: u32,
+            large: i64,
+            huge: u64,
+            pointer: usize,
+            offset: isize,
+            wide: i128,
+        }
+    };
+
+    assert_value! {sizes=>
+        "properties.tiny.format" = r#""int32""#, "i8 format"
+        "properties.tiny.minimum" = r#"null"#, "i8 minimum"
+        "properties.small.format" = r#""int32""#, "u16 format"
+        "properties.small.minimum" = r#"0"#, "u16 minimum"
+        "properties.medium.format" = r#""int32""#, "u32 format"
+        "properties.medium.minimum" = r#"0"#, "u32 minimum"
+        "properties.large.format" = r#""int64""#, "i64 format"
+        "properties.huge.format" = r#""int64""#, "u64 format"
+        "properties.huge.minimum" = r#"0"#, "u64 minimum"
+        "properties.pointer.format" = r#""int64""#, "usize format"
+        "properties.pointer.minimum" = r#"0"#, "usize minimum"
+        "properties.offset.format" = r#""int64""#, "isize format"
+        "properties.offset.minimum" = r#"null"#, "isize minimum"
+        "properties.wide.type" = r#""integer""#, "i128 type"
+        "properties.wide.format" = r#"null"#, "i128 format"
+    };
+}
+
 #[test]
 fn derive_struct_with_default_attr() {
     let book = api_doc! {
@@ -403,6 +558,21 @@ fn derive_struct_unnamed_fields_tuple_with_different_types_success() {
     }
 }
 
+#[test]
+fn derive_struct_unnamed_fields_tuple_with_option_element_keeps_min_items() {
+    // serde serializes `None` tuple elements as `null` and requires them on deserialization,
+    // thus the array length always equals the arity of the tuple struct.
+    let point = api_doc! {
+        struct Point(f64, f64, Option<f64>);
+    };
+
+    assert_value! {point=>
+        "type" = r#""array""#, "Point type"
+        "maxItems" = r#"3"#, "Point max items"
+        "minItems" = r#"3"#, "Point min items"
+    }
+}
+
 #[test]
 fn derive_struct_unnamed_field_with_generic_types_success() {
     let point = api_doc! {
@@ -517,6 +687,29 @@ fn derive_struct_nested_vec_success() {
     common::assert_json_array_len(vecs.get("required").unwrap(), 1);
 }
 
+#[test]
+fn derive_struct_nested_vec_with_option_success() {
+    let matrix = api_doc! {
+        struct Matrix {
+            matrix: Vec<Vec<f64>>,
+            optional_matrix: Option<Vec<Vec<f64>>>,
+            cube: [[[i32; 2]; 2]; 2],
+        }
+    };
+
+    assert_value! {matrix=>
+        "properties.matrix.type" = r#""array""#, "Matrix property type"
+        "properties.matrix.items.type" = r#""array""#, "Matrix property items type"
+        "properties.matrix.items.items.type" = r#""number""#, "Matrix property items item type"
+        "properties.matrix.items.items.format" = r#""double""#, "Matrix property items item format"
+        "properties.optional_matrix.type" = r#"["array","null"]"#, "Optional matrix property type"
+        "properties.optional_matrix.items.type" = r#""array""#, "Optional matrix property items type"
+        "properties.optional_matrix.items.items.type" = r#""number""#, "Optional matrix property items item type"
+        "properties.cube.items.items.items.type" = r#""integer""#, "Cube property items item item type"
+        "required" = r#"["matrix","cube"]"#, "Required properties"
+    }
+}
+
 #[test]
 fn derive_struct_with_example() {
     let pet = api_doc! {
@@ -533,6 +726,81 @@ fn derive_struct_with_example() {
     }
 }
 
+#[test]
+fn derive_struct_with_min_and_max_properties() {
+    let labels = api_doc! {
+        #[derive(serde::Serialize)]
+        #[schema(min_properties = 1, max_properties = 20)]
+        struct Labels {
+            #[serde(flatten)]
+            labels: HashMap<String, String>,
+        }
+    };
+
+    assert_value! {labels=>
+        "additionalProperties.type" = r#""string""#, "Labels additional properties type"
+        "minProperties" = r#"1"#, "Labels min properties"
+        "maxProperties" = r#"20"#, "Labels max properties"
+    }
+}
+
+#[test]
+fn derive_struct_with_item_minimum_and_maximum() {
+    let scores = api_doc! {
+        struct Scores {
+            #[schema(item_minimum = 0, item_maximum = 100)]
+            values: Vec<i32>,
+        }
+    };
+
+    assert_value! {scores=>
+        "properties.values.type" = r#""array""#, "Values type"
+        "properties.values.items.type" = r#""integer""#, "Values items type"
+        "properties.values.items.minimum" = r#"0"#, "Values items minimum"
+        "properties.values.items.maximum" = r#"100"#, "Values items maximum"
+        "properties.values.minimum" = r#"null"#, "Values minimum"
+        "properties.values.maximum" = r#"null"#, "Values maximum"
+    }
+}
+
+#[test]
+fn derive_struct_with_item_example() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    let owner = api_doc! {
+        struct Owner {
+            #[schema(item_example = json!({"name": "bob the cat"}))]
+            pets: Vec<Pet>,
+            #[schema(item_example = "nick")]
+            nicknames: Option<Vec<String>>,
+        }
+    };
+
+    assert_eq!(
+        owner.pointer("/properties/pets/items"),
+        Some(&serde_json::json!({
+            "allOf": [
+                {
+                    "$ref": "#/components/schemas/Pet"
+                }
+            ],
+            "examples": [
+                {
+                    "name": "bob the cat"
+                }
+            ]
+        }))
+    );
+    assert_value! {owner=>
+        "properties.nicknames.items.type" = r#""string""#, "Nicknames items type"
+        "properties.nicknames.items.examples" = r#"["nick"]"#, "Nicknames items examples"
+    }
+}
+
 #[test]
 fn derive_struct_with_deprecated() {
     #[allow(deprecated)]
@@ -555,6 +823,137 @@ fn derive_struct_with_deprecated() {
     }
 }
 
+#[test]
+fn derive_struct_with_deprecated_note() {
+    #[allow(deprecated)]
+    let pet = api_doc! {
+        /// Pet in the store.
+        #[deprecated(since = "1.2.0", note = "Use NewPet instead")]
+        struct Pet {
+            name: String,
+        }
+    };
+
+    assert_value! {pet=>
+        "deprecated" = r#"true"#, "Pet deprecated"
+        "description" = r#""Pet in the store.\n\nDeprecated: Use NewPet instead""#, "Pet description"
+    }
+
+    #[allow(deprecated)]
+    let pet = api_doc! {
+        #[deprecated = "Use NewPet instead"]
+        struct Pet {
+            name: String,
+        }
+    };
+
+    assert_value! {pet=>
+        "deprecated" = r#"true"#, "Pet deprecated"
+        "description" = r#""Deprecated: Use NewPet instead""#, "Pet description"
+    }
+}
+
+#[test]
+fn derive_struct_with_deprecated_note_on_field() {
+    #[allow(deprecated)]
+    let pet = api_doc! {
+        struct Pet {
+            /// Name of the pet.
+            #[deprecated(note = "Use nickname instead")]
+            name: String,
+            #[deprecated = "Use birthday instead"]
+            age: u8,
+        }
+    };
+
+    assert_value! {pet=>
+        "properties.name.deprecated" = r#"true"#, "Pet name deprecated"
+        "properties.name.description" = r#""Name of the pet.\n\nDeprecated: Use nickname instead""#, "Pet name description"
+        "properties.age.deprecated" = r#"true"#, "Pet age deprecated"
+        "properties.age.description" = r#""Deprecated: Use birthday instead""#, "Pet age description"
+    }
+}
+
+#[test]
+fn derive_struct_with_title_on_struct_and_fields() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        name: String,
+    }
+
+    let pet = api_doc! {
+        #[schema(title = "Pet record")]
+        struct Pet {
+            #[schema(title = "Pet name")]
+            name: String,
+            #[schema(title = "Pet owner")]
+            owner: Owner,
+            age: i32,
+        }
+    };
+
+    assert_value! {pet=>
+        "title" = r#""Pet record""#, "Pet title"
+        "properties.name.title" = r#""Pet name""#, "Pet name title"
+        "properties.owner.title" = r#""Pet owner""#, "Pet owner title"
+        "properties.owner.oneOf.[0].$ref" = r##""#/components/schemas/Owner""##, "Pet owner ref"
+        "properties.age.title" = r#"null"#, "Pet age title"
+    }
+}
+
+#[test]
+fn derive_struct_with_doc_attribute_description() {
+    let pet = api_doc! {
+        #[doc = "Pet in the store."]
+        struct Pet {
+            #[doc = "Name of the pet."]
+            name: String,
+        }
+    };
+
+    assert_value! {pet=>
+        "description" = r#""Pet in the store.""#, "Pet description"
+        "properties.name.description" = r#""Name of the pet.""#, "Pet name description"
+    }
+}
+
+#[test]
+fn derive_struct_with_all_of_base() {
+    #[derive(ToSchema)]
+    #[allow(dead_code)]
+    struct Base {
+        id: i64,
+    }
+
+    let pet = api_doc! {
+        #[schema(all_of(Base))]
+        struct Pet {
+            name: String,
+        }
+    };
+
+    assert_eq!(
+        pet,
+        serde_json::json!({
+            "allOf": [
+                {
+                    "$ref": "#/components/schemas/Base"
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "name": {
+                            "type": "string"
+                        }
+                    },
+                    "required": ["name"]
+                }
+            ]
+        })
+    );
+}
+
 #[test]
 fn derive_struct_with_schema_deprecated() {
     let pet = api_doc! {
@@ -685,6 +1084,33 @@ fn derive_struct_with_cow() {
     };
 }
 
+#[test]
+fn derive_struct_with_cow_slice_and_nested_option_box_vec() {
+    #[allow(unused)]
+    #[derive(ToSchema, Clone)]
+    struct Pet {
+        name: String,
+    }
+
+    #[allow(unused)]
+    let owner = api_doc! {
+        #[allow(clippy::box_collection)]
+        struct Owner<'a> {
+            pets: Cow<'a, [Pet]>,
+            previous_pets: Option<Box<Vec<Pet>>>,
+        }
+    };
+
+    common::assert_json_array_len(owner.get("required").unwrap(), 1);
+    assert_value! {owner=>
+        "required.[0]" = r###""pets""###, "Owner required"
+        "properties.pets.type" = r###""array""###, "Owner pets type"
+        "properties.pets.items.$ref" = r###""#/components/schemas/Pet""###, "Owner pets items"
+        "properties.previous_pets.type" = r###"["array","null"]"###, "Owner previous_pets type"
+        "properties.previous_pets.items.$ref" = r###""#/components/schemas/Pet""###, "Owner previous_pets items"
+    };
+}
+
 #[test]
 fn derive_with_box_and_refcell() {
     #[allow(unused)]
@@ -709,6 +1135,48 @@ fn derive_with_box_and_refcell() {
     };
 }
 
+#[test]
+fn derive_struct_with_box_and_optional_box_fields() {
+    #[allow(unused)]
+    #[derive(ToSchema)]
+    struct Pet {
+        name: String,
+    }
+
+    let owner = api_doc! {
+        struct Owner {
+            pet: Box<Pet>,
+            boxed_option: Box<Option<Pet>>,
+            optional_box: Option<Box<Pet>>,
+        }
+    };
+
+    assert_eq!(
+        owner.pointer("/properties"),
+        Some(&serde_json::json!({
+            "pet": {
+                "$ref": "#/components/schemas/Pet"
+            },
+            "boxed_option": {
+                "oneOf": [
+                    { "type": "null" },
+                    { "$ref": "#/components/schemas/Pet" }
+                ]
+            },
+            "optional_box": {
+                "oneOf": [
+                    { "type": "null" },
+                    { "$ref": "#/components/schemas/Pet" }
+                ]
+            }
+        }))
+    );
+    assert_eq!(
+        owner.pointer("/required"),
+        Some(&serde_json::json!(["pet", "boxed_option"]))
+    );
+}
+
 #[test]
 fn derive_struct_with_inline() {
     #[derive(utoipa::ToSchema)]
@@ -733,6 +1201,44 @@ fn derive_struct_with_inline() {
     assert_json_snapshot!(&greeting);
 }
 
+#[test]
+fn derive_struct_with_inline_enum_array_items() {
+    use utoipa::PartialSchema;
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    enum Status {
+        Active,
+        Inactive,
+    }
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Account {
+        #[schema(inline)]
+        statuses: Vec<Status>,
+        history: Vec<Status>,
+    }
+
+    let inline_items = serde_json::json!({
+        "type": "string",
+        "enum": ["Active", "Inactive"]
+    });
+    let account = serde_json::to_value(Account::schema()).expect("Account should serialize");
+
+    assert_eq!(
+        account.pointer("/properties/statuses/items"),
+        Some(&inline_items)
+    );
+    assert_eq!(
+        account.pointer("/properties/history/items/$ref"),
+        Some(&serde_json::json!("#/components/schemas/Status"))
+    );
+
+    let statuses = serde_json::to_value(<Vec<Status>>::schema()).expect("Vec should serialize");
+    assert_eq!(statuses.pointer("/items"), Some(&inline_items));
+}
+
 #[test]
 fn derive_simple_enum() {
     let value: Value = api_doc! {
@@ -1076,6 +1582,36 @@ fn derive_mixed_enum_custom_rename() {
     assert_json_snapshot!(value);
 }
 
+#[test]
+fn derive_enum_variants_custom_rename_all() {
+    let value: Value = api_doc! {
+        #[schema(rename_all = "SCREAMING_SNAKE_CASE")]
+        enum PostStatus {
+            DraftPost,
+            PublishedPost,
+        }
+    };
+
+    assert_value! {value=>
+        "enum" = r#"["DRAFT_POST","PUBLISHED_POST"]"#, "PostStatus enum variants"
+    }
+
+    let value: Value = api_doc! {
+        #[derive(serde::Serialize)]
+        #[serde(tag = "type")]
+        #[schema(rename_all = "SCREAMING_SNAKE_CASE")]
+        enum PostEvent {
+            NewPost { id: i64 },
+            RemovedPost { id: i64 },
+        }
+    };
+
+    assert_value! {value=>
+        "oneOf.[0].properties.type.enum" = r#"["NEW_POST"]"#, "NewPost tag value"
+        "oneOf.[1].properties.type.enum" = r#"["REMOVED_POST"]"#, "RemovedPost tag value"
+    }
+}
+
 #[test]
 fn derive_mixed_enum_use_serde_rename_over_custom_rename() {
     let value: Value = api_doc! {
@@ -1783,6 +2319,49 @@ fn derive_struct_component_field_type_override_with_format_with_vec() {
     }
 }
 
+#[test]
+fn derive_struct_string_field_with_format() {
+    let user = api_doc! {
+        struct User {
+            #[schema(format = "email")]
+            email: String,
+            #[schema(format = Password)]
+            password: String,
+            #[schema(format = Ipv4)]
+            address: String,
+        }
+    };
+
+    assert_value! {user=>
+        "properties.email.type" = r#""string""#, "User email type"
+        "properties.email.format" = r#""email""#, "User email format"
+        "properties.password.format" = r#""password""#, "User password format"
+        "properties.address.format" = r#""ipv4""#, "User address format"
+    }
+}
+
+#[test]
+fn derive_struct_component_field_newtype_override_with_value_type() {
+    #[allow(unused)]
+    struct Email(String);
+
+    let user = api_doc! {
+        struct User {
+            #[schema(value_type = String)]
+            email: Email,
+            #[schema(value_type = Option<String>)]
+            backup_email: Option<Email>,
+        }
+    };
+
+    assert_value! {user=>
+        "properties.email.type" = r#""string""#, "User email type"
+        "properties.email.$ref" = r#"null"#, "User email reference"
+        "properties.backup_email.type" = r#"["string","null"]"#, "User backup email type"
+        "required" = r#"["email"]"#, "User required properties"
+    }
+}
+
 #[test]
 fn derive_unnamed_struct_schema_type_override() {
     let value = api_doc! {
@@ -2006,6 +2585,28 @@ fn derive_parse_serde_field_attributes() {
     assert_json_snapshot!(post);
 }
 
+#[test]
+fn derive_parse_serde_skip_deserializing_field_as_read_only() {
+    let account = api_doc! {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Account {
+            #[serde(skip_deserializing)]
+            id: u64,
+            #[serde(skip_deserializing)]
+            #[schema(read_only = false)]
+            created: String,
+            name: String,
+        }
+    };
+
+    assert_value! {account=>
+        "properties.id.readOnly" = r#"true"#, "Account id read only"
+        "properties.created.readOnly" = r#"false"#, "Account created read only"
+        "properties.name.readOnly" = r#"null"#, "Account name read only"
+        "required" = r#"["id","created","name"]"#, "Account required fields"
+    }
+}
+
 #[test]
 fn derive_parse_serde_simple_enum_attributes() {
     let value = api_doc! {
@@ -2095,6 +2696,80 @@ fn derive_mixed_enum_as() {
     assert_json_snapshot!(&value);
 }
 
+#[test]
+fn derive_to_schema_name_with_as_override() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        id: i32,
+    }
+
+    #[derive(ToSchema)]
+    #[schema(as = pets::Cat)]
+    #[allow(unused)]
+    struct Cat {
+        id: i32,
+    }
+
+    assert_eq!(<Pet as ToSchema>::name(), "Pet");
+    assert_eq!(<Cat as ToSchema>::name(), "pets.Cat");
+    assert_eq!(
+        utoipa::openapi::Ref::from_schema_name(<Cat as ToSchema>::name()).ref_location,
+        "#/components/schemas/pets.Cat"
+    );
+}
+
+#[test]
+fn derive_to_schema_with_as_string_name_referenced_by_field() {
+    #[derive(ToSchema)]
+    #[schema(as = "pets.Pet")]
+    #[allow(unused)]
+    struct Pet {
+        id: i32,
+    }
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        pet: Pet,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Owner)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_eq!(<Pet as ToSchema>::name(), "pets.Pet");
+    assert!(doc.pointer("/components/schemas/pets.Pet").is_some());
+    assert_eq!(
+        doc.pointer("/components/schemas/Owner/properties/pet"),
+        Some(&serde_json::json!({"$ref": "#/components/schemas/pets.Pet"}))
+    );
+}
+
+#[test]
+fn derive_struct_with_option_field_value_type_is_nullable() {
+    #[allow(unused)]
+    struct Id(u64);
+
+    let pet = api_doc! {
+        struct Pet {
+            #[schema(value_type = u64)]
+            id: Option<Id>,
+            #[schema(value_type = u64)]
+            owner_id: Id,
+        }
+    };
+
+    assert_value! {pet=>
+        "properties.id.type" = r#"["integer","null"]"#, "Pet id type"
+        "properties.id.format" = r#""int64""#, "Pet id format"
+        "properties.owner_id.type" = r#""integer""#, "Pet owner_id type"
+        "required" = r#"["owner_id"]"#, "Pet required fields"
+    };
+}
+
 #[test]
 fn derive_component_with_to_schema_value_type() {
     #[derive(ToSchema)]
@@ -2172,6 +2847,23 @@ fn derive_component_with_linked_list() {
     assert_json_snapshot!(example_schema);
 }
 
+#[test]
+fn derive_component_with_vec_deque() {
+    use std::collections::VecDeque;
+
+    let example_schema = api_doc! {
+        struct ExampleSchema {
+            values: VecDeque<i32>
+        }
+    };
+
+    assert_value! {example_schema=>
+        "properties.values.type" = r#""array""#, "ExampleSchema values type"
+        "properties.values.items.type" = r#""integer""#, "ExampleSchema values items type"
+        "properties.values.items.format" = r#""int32""#, "ExampleSchema values items format"
+    }
+}
+
 #[test]
 #[cfg(feature = "smallvec")]
 fn derive_component_with_smallvec_feature() {
@@ -2199,6 +2891,30 @@ fn derive_schema_with_default_field() {
     assert_json_snapshot!(value);
 }
 
+#[test]
+fn derive_schema_with_serde_default_fields_not_required() {
+    fn default_retries() -> u32 {
+        3
+    }
+
+    let value = api_doc! {
+        #[derive(serde::Deserialize)]
+        struct Settings {
+            name: String,
+            #[serde(default)]
+            enabled: bool,
+            #[serde(default = "default_retries")]
+            retries: u32,
+        }
+    };
+
+    assert_value! {value=>
+        "required" = r#"["name"]"#, "Settings required fields"
+        "properties.enabled.type" = r#""boolean""#, "Settings enabled type"
+        "properties.retries.type" = r#""integer""#, "Settings retries type"
+    };
+}
+
 #[test]
 fn derive_schema_with_default_struct() {
     let value = api_doc! {
@@ -2242,6 +2958,63 @@ fn derive_schema_for_repr_enum() {
     assert_json_snapshot!(value);
 }
 
+#[test]
+fn derive_schema_for_enum_with_explicit_discriminants_without_repr() {
+    let value: Value = api_doc! {
+        enum Level {
+            Low = 1,
+            Medium = 2,
+            High = 3,
+        }
+    };
+
+    assert_value! {value=>
+        "enum" = r#"["Low","Medium","High"]"#, "Level enum variants"
+        "type" = r#""string""#, "Level enum type"
+    };
+}
+
+#[test]
+fn derive_schema_for_repr_enum_with_suffixed_discriminants() {
+    let value: Value = api_doc! {
+        #[repr(u8)]
+        enum Small {
+            A = 1u8,
+            B = 2u8,
+        }
+    };
+
+    #[cfg(feature = "repr")]
+    assert_value! {value=>
+        "enum" = r#"[1,2]"#, "Small enum variants"
+        "type" = r#""integer""#, "Small enum type"
+    };
+    #[cfg(not(feature = "repr"))]
+    assert_value! {value=>
+        "enum" = r#"["A","B"]"#, "Small enum variants"
+        "type" = r#""string""#, "Small enum type"
+    };
+}
+
+#[test]
+fn derive_schema_for_serde_renamed_enum_with_explicit_discriminants() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(rename_all = "lowercase")]
+        enum Level {
+            Low = 1,
+            #[serde(rename = "mid")]
+            Medium = 2,
+            High = 3,
+        }
+    };
+
+    assert_value! {value=>
+        "enum" = r#"["low","mid","high"]"#, "Level enum variants"
+        "type" = r#""string""#, "Level enum type"
+    };
+}
+
 #[test]
 #[cfg(feature = "repr")]
 fn derive_schema_for_tagged_repr_enum() {
@@ -2279,6 +3052,27 @@ fn derive_schema_for_skipped_repr_enum() {
     };
 }
 
+#[test]
+#[cfg(feature = "repr")]
+fn derive_schema_for_repr_enum_with_enum_varnames() {
+    let value: Value = api_doc! {
+        #[derive(serde::Deserialize, serde::Serialize)]
+        #[repr(i32)]
+        #[schema(enum_varnames)]
+        enum ExitCode {
+            Error  = -1,
+            Ok     = 0,
+            #[serde(skip)]
+            Unknown = 1,
+        }
+    };
+
+    assert_value! {value=>
+        "enum" = r#"[-1,0]"#, "ExitCode enum variants"
+        "x-enum-varnames" = r#"["Error","Ok"]"#, "ExitCode enum varnames"
+    };
+}
+
 #[test]
 #[cfg(feature = "repr")]
 fn derive_repr_enum_with_with_custom_default_fn_success() {
@@ -2326,6 +3120,43 @@ fn derive_repr_enum_with_with_custom_default_fn_and_example() {
     };
 }
 
+#[test]
+fn derive_struct_with_example_from_fn() {
+    use utoipa::PartialSchema;
+
+    fn pet_sample() -> serde_json::Value {
+        serde_json::json!({ "id": 1, "name": "bob the cat" })
+    }
+
+    fn name_sample() -> serde_json::Value {
+        serde_json::json!("bob")
+    }
+
+    #[derive(ToSchema)]
+    #[schema(example = pet_sample())]
+    #[allow(unused)]
+    struct Pet {
+        id: u64,
+        #[schema(example = name_sample, examples(name_sample(), "alice"))]
+        name: String,
+    }
+
+    let value = serde_json::to_value(Pet::schema()).expect("Pet schema should serialize");
+
+    assert_eq!(
+        value.pointer("/example"),
+        Some(&serde_json::json!({ "id": 1, "name": "bob the cat" }))
+    );
+    assert_eq!(
+        value.pointer("/properties/name/example"),
+        Some(&serde_json::json!("bob"))
+    );
+    assert_eq!(
+        value.pointer("/properties/name/examples"),
+        Some(&serde_json::json!(["bob", "alice"]))
+    );
+}
+
 #[test]
 fn derive_struct_with_vec_field_with_example() {
     let post = api_doc! {
@@ -2412,6 +3243,83 @@ fn derive_struct_with_self_reference() {
     assert_json_snapshot!(value);
 }
 
+#[test]
+fn derive_recursive_struct_with_vec_of_self() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Node {
+        value: i32,
+        children: Vec<Node>,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Node)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = doc.pointer("/components/schemas").unwrap();
+
+    assert_eq!(
+        schemas,
+        &serde_json::json!({
+            "Node": {
+                "type": "object",
+                "properties": {
+                    "value": {
+                        "type": "integer",
+                        "format": "int32"
+                    },
+                    "children": {
+                        "type": "array",
+                        "items": {
+                            "$ref": "#/components/schemas/Node"
+                        }
+                    }
+                },
+                "required": ["value", "children"]
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_recursive_generic_struct_with_vec_of_self() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Node<T> {
+        value: T,
+        children: Vec<Node<T>>,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Node<String>)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = doc.pointer("/components/schemas").unwrap();
+
+    assert_eq!(
+        schemas,
+        &serde_json::json!({
+            "Node_String": {
+                "type": "object",
+                "properties": {
+                    "value": {
+                        "type": "string"
+                    },
+                    "children": {
+                        "type": "array",
+                        "items": {
+                            "$ref": "#/components/schemas/Node_String"
+                        }
+                    }
+                },
+                "required": ["value", "children"]
+            }
+        })
+    );
+}
+
 #[test]
 fn derive_unnamed_struct_with_self_reference() {
     let value = api_doc! {
@@ -3260,3 +4168,67 @@ fn test_new_type_struct_pattern() {
 
     assert_json_snapshot!(value);
 }
+
+#[test]
+fn derive_struct_with_deref_uses_target_schema() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    struct Owner {
+        name: String,
+    }
+
+    #[derive(ToSchema)]
+    struct Pet {
+        id: u64,
+        owner: Owner,
+    }
+
+    #[derive(ToSchema)]
+    #[schema(deref)]
+    struct PetWrapper {
+        pet: Pet,
+        cached: bool,
+    }
+
+    impl std::ops::Deref for PetWrapper {
+        type Target = Pet;
+
+        fn deref(&self) -> &Self::Target {
+            &self.pet
+        }
+    }
+
+    #[derive(ToSchema)]
+    #[schema(deref)]
+    struct PetRef(Box<Pet>);
+
+    impl std::ops::Deref for PetRef {
+        type Target = Pet;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    use utoipa::PartialSchema;
+    let pet = serde_json::to_value(<Pet as PartialSchema>::schema()).unwrap();
+    let wrapper = serde_json::to_value(<PetWrapper as PartialSchema>::schema()).unwrap();
+    let pet_ref = serde_json::to_value(<PetRef as PartialSchema>::schema()).unwrap();
+
+    assert_eq!(wrapper, pet, "expected deref struct to use schema of Pet");
+    assert_eq!(pet_ref, pet, "expected deref struct to use schema of Pet");
+
+    let mut schemas = Vec::new();
+    <PetWrapper as ToSchema>::schemas(&mut schemas);
+    let names = schemas
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        names,
+        vec!["Owner"],
+        "expected schemas of Pet to be collected"
+    );
+}
@@ -24,6 +24,43 @@ fn generic_schema_custom_bound() {
     assert_is_to_schema::<Type<NoToSchema>>();
 }
 
+#[test]
+fn generic_schema_serde_bound() {
+    #![allow(unused)]
+
+    trait Named {
+        fn name() -> &'static str;
+    }
+
+    #[derive(Serialize, ToSchema)]
+    #[schema(bound = "T: Named + ToSchema")]
+    struct Tagged<T> {
+        value: T,
+    }
+
+    #[derive(Serialize, ToSchema)]
+    #[serde(bound = "T: Serialize + Named")]
+    struct Wrapper<T> {
+        tagged: Tagged<T>,
+    }
+
+    #[derive(Serialize, ToSchema)]
+    struct Pet {
+        name: String,
+    }
+
+    impl Named for Pet {
+        fn name() -> &'static str {
+            "pet"
+        }
+    }
+
+    fn assert_is_to_schema<T: ToSchema>() {}
+
+    assert_is_to_schema::<Wrapper<Pet>>();
+    assert_eq!(Wrapper::<Pet>::name(), "Wrapper");
+}
+
 #[test]
 fn generic_request_body_schema() {
     #![allow(unused)]
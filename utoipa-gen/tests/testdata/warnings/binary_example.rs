@@ -0,0 +1,12 @@
+use utoipa::ToSchema;
+
+#[derive(ToSchema)]
+#[allow(unused)]
+struct Upload {
+    #[schema(value_type = String, format = Binary, example = "file content")]
+    file: Vec<u8>,
+    #[schema(format = Byte, example = "ZmlsZQ==")]
+    encoded: String,
+}
+
+fn main() {}
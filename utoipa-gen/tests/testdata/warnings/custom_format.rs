@@ -0,0 +1,14 @@
+use utoipa::ToSchema;
+
+#[derive(ToSchema)]
+#[allow(unused)]
+struct Post {
+    #[schema(format = "datetime")]
+    created: String,
+    #[schema(format = "date-time")]
+    updated: String,
+    #[schema(format = "my-custom-format")]
+    custom: String,
+}
+
+fn main() {}
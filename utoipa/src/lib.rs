@@ -558,6 +558,22 @@ where
     }
 }
 
+#[cfg(feature = "macros")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
+impl<T: ToSchema> ToSchema for std::collections::VecDeque<T>
+where
+    std::collections::VecDeque<T>: PartialSchema,
+{
+    fn schemas(
+        schemas: &mut Vec<(
+            String,
+            utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+        )>,
+    ) {
+        T::schemas(schemas);
+    }
+}
+
 #[cfg(feature = "macros")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
 impl<T: ToSchema> ToSchema for [T]
@@ -1260,8 +1276,12 @@ impl_from_for_number!(
 pub mod __dev {
     use utoipa_gen::schema;
 
+    use crate::openapi::schema::Schema;
+    use crate::openapi::RefOr;
     use crate::{utoipa, OpenApi, PartialSchema};
 
+    use super::BTreeMap;
+
     pub trait PathConfig {
         fn path() -> String;
 
@@ -1326,6 +1346,31 @@ pub mod __dev {
         }
     }
 
+    /// Assert that type listed in _`components(schemas(...))`_ of the `OpenApi` derive or in
+    /// _`body = ...`_ of the `path` macro implements `ToSchema`. If this fails to compile derive
+    /// `ToSchema` for the type or implement it manually.
+    pub const fn assert_to_schema<T: crate::ToSchema>() {}
+
+    /// Extend `components` with `schemas` asserting in debug builds that single schema name is
+    /// not registered with two different schemas. The assertion is a `debug_assert!` thus in
+    /// release builds it never fires and the collision silently overwrites the former schema
+    /// with the latter.
+    pub fn extend_schemas<I: IntoIterator<Item = (String, RefOr<Schema>)>>(
+        components: &mut BTreeMap<String, RefOr<Schema>>,
+        schemas: I,
+    ) {
+        for (name, schema) in schemas {
+            if let Some(existing) = components.get(&name) {
+                debug_assert!(
+                    existing == &schema,
+                    "schema name `{name}` is registered by multiple different schemas, \
+                    consider renaming one of them with `#[schema(as = ...)]`"
+                );
+            }
+            components.insert(name, schema);
+        }
+    }
+
     pub trait ComposeSchema {
         fn compose(
             new_generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
@@ -1407,6 +1452,16 @@ pub mod __dev {
         }
     }
 
+    impl<T: ComposeSchema> ComposeSchema for std::collections::VecDeque<T> {
+        fn compose(
+            schemas: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            utoipa::openapi::schema::ArrayBuilder::new()
+                .items(schema_or_compose::<T>(schemas, 0))
+                .into()
+        }
+    }
+
     impl<T: ComposeSchema> ComposeSchema for [T] {
         fn compose(
             schemas: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
@@ -1605,11 +1660,12 @@ mod tests {
         assert_compact_json_snapshot!(i32::schema(), @r#"{"type": "integer", "format": "int32"}"#);
         assert_compact_json_snapshot!(i64::schema(), @r#"{"type": "integer", "format": "int64"}"#);
         assert_compact_json_snapshot!(i128::schema(), @r#"{"type": "integer"}"#);
-        assert_compact_json_snapshot!(isize::schema(), @r#"{"type": "integer"}"#);
+        assert_compact_json_snapshot!(isize::schema(), @r#"{"type": "integer", "format": "int64"}"#);
         assert_compact_json_snapshot!(u8::schema(), @r#"{"type": "integer", "format": "int32", "minimum": 0}"#);
         assert_compact_json_snapshot!(u16::schema(), @r#"{"type": "integer", "format": "int32", "minimum": 0}"#);
         assert_compact_json_snapshot!(u32::schema(), @r#"{"type": "integer", "format": "int32", "minimum": 0}"#);
         assert_compact_json_snapshot!(u64::schema(), @r#"{"type": "integer", "format": "int64", "minimum": 0}"#);
+        assert_compact_json_snapshot!(usize::schema(), @r#"{"type": "integer", "format": "int64", "minimum": 0}"#);
     }
 
     #[cfg(feature = "non_strict_integers")]
@@ -1620,11 +1676,12 @@ mod tests {
         assert_compact_json_snapshot!(i32::schema(), @r#"{"type": "integer", "format": "int32"}"#);
         assert_compact_json_snapshot!(i64::schema(), @r#"{"type": "integer", "format": "int64"}"#);
         assert_compact_json_snapshot!(i128::schema(), @r#"{"type": "integer"}"#);
-        assert_compact_json_snapshot!(isize::schema(), @r#"{"type": "integer"}"#);
+        assert_compact_json_snapshot!(isize::schema(), @r#"{"type": "integer", "format": "int64"}"#);
         assert_compact_json_snapshot!(u8::schema(), @r#"{"type": "integer", "format": "uint8", "minimum": 0}"#);
         assert_compact_json_snapshot!(u16::schema(), @r#"{"type": "integer", "format": "uint16", "minimum": 0}"#);
-        assert_compact_json_snapshot!(u32::schema(), @r#"{"type": "integer", "format": "int32", "minimum": 0}"#);
-        assert_compact_json_snapshot!(u64::schema(), @r#"{"type": "integer", "format": "int64", "minimum": 0}"#);
+        assert_compact_json_snapshot!(u32::schema(), @r#"{"type": "integer", "format": "uint32", "minimum": 0}"#);
+        assert_compact_json_snapshot!(u64::schema(), @r#"{"type": "integer", "format": "uint64", "minimum": 0}"#);
+        assert_compact_json_snapshot!(usize::schema(), @r#"{"type": "integer", "format": "uint64", "minimum": 0}"#);
     }
 
     #[test]
@@ -1633,7 +1690,11 @@ mod tests {
             ("bool", bool::schema(), json!({"type": "boolean"})),
             ("str", str::schema(), json!({"type": "string"})),
             ("String", String::schema(), json!({"type": "string"})),
-            ("char", char::schema(), json!({"type": "string"})),
+            (
+                "char",
+                char::schema(),
+                json!({"type": "string", "maxLength": 1}),
+            ),
             (
                 "f32",
                 f32::schema(),
@@ -39,6 +39,7 @@ pub mod schema;
 pub mod security;
 pub mod server;
 pub mod tag;
+pub mod validation;
 pub mod xml;
 
 builder! {
@@ -163,6 +164,31 @@ impl OpenApi {
         serde_json::to_string_pretty(self)
     }
 
+    /// Writes this [`OpenApi`] as JSON to the given writer. This method essentially calls
+    /// [`serde_json::to_writer`] method.
+    ///
+    /// This avoids building an intermediate [`String`] e.g. when writing large documents directly
+    /// to a file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{Info, Paths, OpenApi};
+    /// let openapi = OpenApi::new(Info::new("pet api", "0.1.0"), Paths::new());
+    ///
+    /// let mut buffer = Vec::new();
+    /// openapi.write_json(&mut buffer).expect("OpenApi should serialize");
+    /// ```
+    pub fn write_json<W: std::io::Write>(&self, writer: W) -> Result<(), serde_json::Error> {
+        serde_json::to_writer(writer, self)
+    }
+
+    /// Writes this [`OpenApi`] as pretty JSON to the given writer. This method essentially calls
+    /// [`serde_json::to_writer_pretty`] method.
+    pub fn write_pretty_json<W: std::io::Write>(&self, writer: W) -> Result<(), serde_json::Error> {
+        serde_json::to_writer_pretty(writer, self)
+    }
+
     /// Converts this [`OpenApi`] to YAML String. This method essentially calls [`serde_norway::to_string`] method.
     #[cfg(feature = "yaml")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "yaml")))]
@@ -170,6 +196,447 @@ impl OpenApi {
         serde_norway::to_string(self)
     }
 
+    /// Writes this [`OpenApi`] as YAML to the given writer. This method essentially calls
+    /// [`serde_norway::to_writer`] method.
+    #[cfg(feature = "yaml")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "yaml")))]
+    pub fn write_yaml<W: std::io::Write>(&self, writer: W) -> Result<(), serde_norway::Error> {
+        serde_norway::to_writer(writer, self)
+    }
+
+    /// Parse [`OpenApi`] from YAML string. This method essentially calls [`serde_norway::from_str`] method.
+    ///
+    /// This can be used to load an existing OpenAPI YAML document e.g. for merging it with the
+    /// generated one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::OpenApi;
+    /// let yaml = r#"
+    /// openapi: 3.1.0
+    /// info:
+    ///   title: pet api
+    ///   version: 0.1.0
+    /// paths: {}
+    /// "#;
+    /// let openapi = OpenApi::from_yaml(yaml).expect("valid OpenAPI YAML");
+    /// assert_eq!(openapi.info.title, "pet api");
+    /// ```
+    #[cfg(feature = "yaml")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "yaml")))]
+    pub fn from_yaml(yaml: &str) -> Result<Self, serde_norway::Error> {
+        serde_norway::from_str(yaml)
+    }
+
+    /// Get iterator over the [`SecurityScheme`][security_scheme]s of this [`OpenApi`].
+    ///
+    /// This delegates to [`Components::security_schemes`] and returns an empty iterator when
+    /// [`OpenApi::components`] is not defined.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{ComponentsBuilder, OpenApiBuilder};
+    /// # use utoipa::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+    /// let openapi = OpenApiBuilder::new()
+    ///     .components(Some(
+    ///         ComponentsBuilder::new()
+    ///             .security_scheme(
+    ///                 "api_key",
+    ///                 SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("x-api-key"))),
+    ///             )
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    ///
+    /// let names = openapi.security_schemes().map(|(name, _)| name.as_str()).collect::<Vec<_>>();
+    /// assert_eq!(names, ["api_key"]);
+    /// ```
+    ///
+    /// [security_scheme]: security/enum.SecurityScheme.html
+    pub fn security_schemes(&self) -> impl Iterator<Item = (&String, &security::SecurityScheme)> {
+        self.components
+            .iter()
+            .flat_map(|components| components.security_schemes.iter())
+    }
+
+    /// Get registered [`Tag`]s of the [`OpenApi`]. Returns empty slice if no tags are defined.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{OpenApiBuilder, tag::Tag};
+    /// let openapi = OpenApiBuilder::new()
+    ///     .tags(Some([Tag::new("pet"), Tag::new("store")]))
+    ///     .build();
+    ///
+    /// let names = openapi.tags().iter().map(|tag| tag.name.as_str()).collect::<Vec<_>>();
+    /// assert_eq!(names, ["pet", "store"]);
+    /// ```
+    pub fn tags(&self) -> &[Tag] {
+        self.tags.as_deref().unwrap_or_default()
+    }
+
+    /// Find registered [`Tag`] by its name. Returns `None` if no tag with given name exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{OpenApiBuilder, tag::TagBuilder};
+    /// let openapi = OpenApiBuilder::new()
+    ///     .tags(Some([TagBuilder::new()
+    ///         .name("pet")
+    ///         .description(Some("Pet operations"))
+    ///         .build()]))
+    ///     .build();
+    ///
+    /// let pet = openapi.get_tag("pet").expect("pet tag is registered");
+    /// assert_eq!(pet.description.as_deref(), Some("Pet operations"));
+    /// assert!(openapi.get_tag("store").is_none());
+    /// ```
+    pub fn get_tag(&self, name: &str) -> Option<&Tag> {
+        self.tags().iter().find(|tag| tag.name == name)
+    }
+
+    /// Insert or replace named schema in [`OpenApi::components`] creating the [`Components`] if
+    /// not yet defined.
+    ///
+    /// This is useful e.g. within [`Modify`][modify] to override schemas with ones loaded at
+    /// runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{Info, Paths, OpenApi, Object, Type};
+    /// let mut openapi = OpenApi::new(Info::new("pet api", "0.1.0"), Paths::new());
+    /// openapi.replace_schema("Pet", Object::with_type(Type::Object));
+    /// ```
+    ///
+    /// [modify]: ../trait.Modify.html
+    pub fn replace_schema<N: Into<String>, S: Into<RefOr<Schema>>>(&mut self, name: N, schema: S) {
+        self.components
+            .get_or_insert_with(Components::new)
+            .add_schema(name, schema);
+    }
+
+    /// Visit every [`Schema`] of the [`OpenApi`] with mutable access.
+    ///
+    /// This walks through schemas in [`Components`] as well as inline schemas of parameters,
+    /// request bodies, responses and headers in [`Paths`]. Nested schemas such as object
+    /// properties, array items and composite schema items are visited after their parent schema.
+    ///
+    /// References ([`RefOr::Ref`]) are not followed, which guarantees that recursive schemas
+    /// referencing themselves will not cause infinite recursion. The referenced schema is still
+    /// visited once via [`Components`].
+    ///
+    /// This is useful e.g. within [`Modify`][modify] to do bulk changes to all schemas.
+    ///
+    /// # Examples
+    ///
+    /// _**Add title to every object schema.**_
+    /// ```rust
+    /// # use utoipa::openapi::{Info, Paths, OpenApi, Object, Schema, Type};
+    /// let mut openapi = OpenApi::new(Info::new("pet api", "0.1.0"), Paths::new());
+    /// openapi.replace_schema("Pet", Object::with_type(Type::Object));
+    ///
+    /// openapi.walk_schemas(|schema| {
+    ///     if let Schema::Object(object) = schema {
+    ///         object.title = Some("Titled".to_string());
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// [modify]: ../trait.Modify.html
+    pub fn walk_schemas<F: FnMut(&mut Schema)>(&mut self, f: F) {
+        self.walk(&mut walk::SchemaVisitor(f));
+    }
+
+    /// Rename schema in [`Components`] from `old` to `new` and rewrite all `$ref`s pointing to
+    /// the `old` schema within the [`OpenApi`] to point to the `new` schema.
+    ///
+    /// This is useful e.g. to reconcile naming of schemas across merged [`OpenApi`] documents.
+    /// References are rewritten even if the `old` schema does not exist in [`Components`].
+    ///
+    /// Returns `false` without changing anything if a schema with the `new` name already exists
+    /// in [`Components`] since renaming would silently overwrite it. Otherwise returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{Info, Paths, OpenApi, Object, ObjectBuilder, Ref, Type};
+    /// let mut openapi = OpenApi::new(Info::new("pet api", "0.1.0"), Paths::new());
+    /// openapi.replace_schema("Pet", Object::with_type(Type::Object));
+    /// openapi.replace_schema(
+    ///     "Owner",
+    ///     ObjectBuilder::new().property("pet", Ref::from_schema_name("Pet")),
+    /// );
+    ///
+    /// assert!(openapi.rename_component("Pet", "Animal"));
+    ///
+    /// let components = openapi.components.as_ref().unwrap();
+    /// assert!(components.schemas.contains_key("Animal"));
+    /// assert!(!components.schemas.contains_key("Pet"));
+    ///
+    /// // `Owner` already exists thus `Animal` is not renamed.
+    /// assert!(!openapi.rename_component("Animal", "Owner"));
+    /// ```
+    pub fn rename_component<O: AsRef<str>, N: Into<String>>(&mut self, old: O, new: N) -> bool {
+        let old = old.as_ref();
+        let new = new.into();
+
+        if old == new {
+            return true;
+        }
+
+        if let Some(components) = self.components.as_mut() {
+            if components.schemas.contains_key(&new) {
+                return false;
+            }
+            if let Some(schema) = components.schemas.remove(old) {
+                components.schemas.insert(new.clone(), schema);
+            }
+        }
+
+        let old_location = Ref::from_schema_name(old).ref_location;
+        let new_location = Ref::from_schema_name(new).ref_location;
+        self.walk(&mut walk::RefVisitor(|reference: &mut Ref| {
+            if reference.ref_location == old_location {
+                reference.ref_location.clone_from(&new_location);
+            }
+        }));
+
+        true
+    }
+
+    fn walk<V: walk::Visit>(&mut self, v: &mut V) {
+        walk::openapi(self, v)
+    }
+
+    /// Call `f` for every [`Operation`][operation] of every [`PathItem`] in the [`OpenApi`]
+    /// along with the path and the [`HttpMethod`] of the operation.
+    ///
+    /// This is useful e.g. within [`Modify`][modify] to do cross-cutting changes to all
+    /// operations such as adding a common response.
+    ///
+    /// # Examples
+    ///
+    /// _**Add 500 response to every operation.**_
+    /// ```rust
+    /// # use utoipa::openapi::{HttpMethod, Info, OpenApi, PathItem, PathsBuilder, Response};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let mut openapi = OpenApi::new(
+    ///     Info::new("pet api", "0.1.0"),
+    ///     PathsBuilder::new().path(
+    ///         "/pets",
+    ///         PathItem::new(HttpMethod::Get, OperationBuilder::new().operation_id(Some("get_pets"))),
+    ///     ),
+    /// );
+    ///
+    /// openapi.for_each_operation(|_, _, operation| {
+    ///     operation
+    ///         .responses
+    ///         .responses
+    ///         .insert("500".to_string(), Response::new("Internal server error").into());
+    /// });
+    /// ```
+    ///
+    /// [operation]: path/struct.Operation.html
+    /// [modify]: ../trait.Modify.html
+    pub fn for_each_operation<F: FnMut(&str, HttpMethod, &mut path::Operation)>(
+        &mut self,
+        mut f: F,
+    ) {
+        for (path, path_item) in self.paths.paths.iter_mut() {
+            [
+                (HttpMethod::Get, &mut path_item.get),
+                (HttpMethod::Put, &mut path_item.put),
+                (HttpMethod::Post, &mut path_item.post),
+                (HttpMethod::Delete, &mut path_item.delete),
+                (HttpMethod::Options, &mut path_item.options),
+                (HttpMethod::Head, &mut path_item.head),
+                (HttpMethod::Patch, &mut path_item.patch),
+                (HttpMethod::Trace, &mut path_item.trace),
+            ]
+            .into_iter()
+            .filter_map(|(method, operation)| {
+                operation.as_mut().map(|operation| (method, operation))
+            })
+            .for_each(|(method, operation)| f(path, method, operation));
+        }
+    }
+
+    /// Add _`response`_ with given _`status`_ to every [`Operation`][operation] of the [`OpenApi`].
+    ///
+    /// Operations already having a response with same _`status`_ are left untouched. This is
+    /// useful e.g. within [`Modify`][modify] to document a common error response.
+    ///
+    /// # Examples
+    ///
+    /// _**Add 500 response to every operation.**_
+    /// ```rust
+    /// # use utoipa::openapi::{HttpMethod, Info, OpenApi, PathItem, PathsBuilder, Response};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let mut openapi = OpenApi::new(
+    ///     Info::new("pet api", "0.1.0"),
+    ///     PathsBuilder::new().path(
+    ///         "/pets",
+    ///         PathItem::new(HttpMethod::Get, OperationBuilder::new().operation_id(Some("get_pets"))),
+    ///     ),
+    /// );
+    ///
+    /// openapi.add_response_to_all("500", Response::new("Internal server error"));
+    /// ```
+    ///
+    /// [operation]: path/struct.Operation.html
+    /// [modify]: ../trait.Modify.html
+    pub fn add_response_to_all<S: Into<String>, R: Into<RefOr<Response>>>(
+        &mut self,
+        status: S,
+        response: R,
+    ) {
+        let status = status.into();
+        let response = response.into();
+        self.for_each_operation(|_, _, operation| {
+            operation
+                .responses
+                .responses
+                .entry(status.clone())
+                .or_insert_with(|| response.clone());
+        });
+    }
+
+    /// Wrap response body schema of every [`Operation`][operation] in an envelope object
+    /// holding the original body under property _`key`_.
+    ///
+    /// This is useful within [`Modify`][modify] when the API wraps all payloads e.g. in
+    /// `{ "data": <body> }`. Only responses defined inline in the operation are wrapped,
+    /// [`Ref`]erenced responses are left untouched. Likewise only JSON content, `application/json`
+    /// and `+json` suffixed media types, is wrapped since the envelope cannot describe e.g.
+    /// `text/plain` or `application/octet-stream` bodies.
+    ///
+    /// # Examples
+    ///
+    /// _**Wrap all response bodies under `data` property.**_
+    /// ```rust
+    /// # use utoipa::openapi::{HttpMethod, Info, OpenApi, PathItem, PathsBuilder, Ref};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// # use utoipa::openapi::response::ResponseBuilder;
+    /// # use utoipa::openapi::content::ContentBuilder;
+    /// let mut openapi = OpenApi::new(
+    ///     Info::new("pet api", "0.1.0"),
+    ///     PathsBuilder::new().path(
+    ///         "/pets",
+    ///         PathItem::new(
+    ///             HttpMethod::Get,
+    ///             OperationBuilder::new().operation_id(Some("get_pets")).response(
+    ///                 "200",
+    ///                 ResponseBuilder::new().content(
+    ///                     "application/json",
+    ///                     ContentBuilder::new().schema(Some(Ref::from_schema_name("Pet"))).build(),
+    ///                 ),
+    ///             ),
+    ///         ),
+    ///     ),
+    /// );
+    ///
+    /// openapi.wrap_response_bodies("data");
+    /// ```
+    ///
+    /// [operation]: path/struct.Operation.html
+    /// [modify]: ../trait.Modify.html
+    pub fn wrap_response_bodies<K: Into<String>>(&mut self, key: K) {
+        let key = key.into();
+        self.for_each_operation(|_, _, operation| {
+            operation
+                .responses
+                .responses
+                .values_mut()
+                .filter_map(|response| match response {
+                    RefOr::T(response) => Some(response),
+                    RefOr::Ref(_) => None,
+                })
+                .flat_map(|response| response.content.iter_mut())
+                .filter(|(media_type, _)| is_json_media_type(media_type))
+                .for_each(|(_, content)| {
+                    if let Some(schema) = content.schema.take() {
+                        content.schema = Some(
+                            ObjectBuilder::new()
+                                .property(key.as_str(), schema)
+                                .required(key.as_str())
+                                .into(),
+                        );
+                    }
+                });
+        });
+    }
+
+    /// Compare `self` to `other` [`OpenApi`] ignoring the order of map entries such as paths,
+    /// properties and content types.
+    ///
+    /// Both documents are compared by their serialized JSON form, so two documents are equal if
+    /// they would produce the same OpenAPI spec regardless of the insertion order of the items.
+    /// This is mostly useful in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{HttpMethod, Info, OpenApi, PathItem, PathsBuilder};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let path = |operation_id: &str| {
+    ///     PathItem::new(
+    ///         HttpMethod::Get,
+    ///         OperationBuilder::new().operation_id(Some(operation_id)),
+    ///     )
+    /// };
+    /// let first = OpenApi::new(
+    ///     Info::new("pet api", "0.1.0"),
+    ///     PathsBuilder::new()
+    ///         .path("/pets", path("get_pets"))
+    ///         .path("/owners", path("get_owners")),
+    /// );
+    /// let second = OpenApi::new(
+    ///     Info::new("pet api", "0.1.0"),
+    ///     PathsBuilder::new()
+    ///         .path("/owners", path("get_owners"))
+    ///         .path("/pets", path("get_pets")),
+    /// );
+    ///
+    /// assert!(first.semantically_eq(&second));
+    /// ```
+    pub fn semantically_eq(&self, other: &OpenApi) -> bool {
+        match (serde_json::to_value(self), serde_json::to_value(other)) {
+            (Ok(this), Ok(other)) => this == other,
+            _ => false,
+        }
+    }
+
+    /// Validate the [`OpenApi`] document returning all found problems as
+    /// [`ValidationError`][validation::ValidationError]s.
+    ///
+    /// Following checks are performed:
+    /// * Local `$ref`s must resolve to an existing location within the document.
+    /// * _`operationId`_s must be unique across all operations.
+    /// * Path parameters must match the `{placeholder}`s of the path and vice versa.
+    /// * Parameter examples must match the type of the parameter's inline schema.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{Info, OpenApi, Paths};
+    /// let openapi = OpenApi::new(Info::new("pet api", "0.1.0"), Paths::new());
+    ///
+    /// assert!(openapi.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<validation::ValidationError>> {
+        let errors = validation::validate(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Merge `other` [`OpenApi`] moving `self` and returning combined [`OpenApi`].
     ///
     /// In functionality wise this is exactly same as calling [`OpenApi::merge`] but but provides
@@ -204,25 +671,10 @@ impl OpenApi {
             self.paths.merge(other.paths);
         };
 
-        if let Some(other_components) = &mut other.components {
-            let components = self.components.get_or_insert(Components::default());
-
-            other_components
-                .schemas
-                .retain(|name, _| !components.schemas.contains_key(name));
-            components.schemas.append(&mut other_components.schemas);
-
-            other_components
-                .responses
-                .retain(|name, _| !components.responses.contains_key(name));
-            components.responses.append(&mut other_components.responses);
-
-            other_components
-                .security_schemes
-                .retain(|name, _| !components.security_schemes.contains_key(name));
-            components
-                .security_schemes
-                .append(&mut other_components.security_schemes);
+        if let Some(other_components) = other.components.take() {
+            self.components
+                .get_or_insert(Components::default())
+                .merge(other_components, schema::MergePolicy::KeepExisting);
         }
 
         if let Some(other_security) = &mut other.security {
@@ -320,6 +772,242 @@ impl OpenApi {
     }
 }
 
+/// Whether the media type is `application/json` or has a `+json` structured syntax suffix.
+fn is_json_media_type(media_type: &str) -> bool {
+    let essence = media_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    essence == "application/json" || essence.ends_with("+json")
+}
+
+mod walk {
+    use super::{
+        path::{Operation, Parameter},
+        request_body::RequestBody,
+        schema::{AdditionalProperties, ArrayItems},
+        Content, OpenApi, Ref, RefOr, Response, Schema,
+    };
+
+    /// Generate the [`Visit`] trait and walk functions over either mutable or shared references
+    /// so that both walks share the same traversal.
+    macro_rules! walk {
+        ( [ $( $mut:tt )? ] $iter:ident, $values:ident, $as_ref:ident, $as_deref:ident ) => {
+            /// Visitor of the [`Schema`]s and [`Ref`]s found while walking through the
+            /// [`OpenApi`].
+            pub(in crate::openapi) trait Visit {
+                fn visit_schema(&mut self, _schema: & $( $mut )? Schema) {}
+
+                fn visit_ref(&mut self, _reference: & $( $mut )? Ref) {}
+            }
+
+            pub(in crate::openapi) fn openapi<V: Visit>(openapi: & $( $mut )? OpenApi, v: &mut V) {
+                if let Some(components) = openapi.components.$as_ref() {
+                    components
+                        .schemas
+                        .$values()
+                        .for_each(|schema| ref_or_schema(schema, v));
+                    components
+                        .responses
+                        .$values()
+                        .for_each(|response| ref_or_response(response, v));
+                    components
+                        .parameters
+                        .$values()
+                        .for_each(|parameter| ref_or_parameter(parameter, v));
+                    components
+                        .request_bodies
+                        .$values()
+                        .for_each(|request_body| ref_or_request_body(request_body, v));
+                }
+
+                for path_item in openapi.paths.paths.$values() {
+                    path_item
+                        .parameters
+                        .$iter()
+                        .flatten()
+                        .for_each(|parameter| self::parameter(parameter, v));
+
+                    [
+                        & $( $mut )? path_item.get,
+                        & $( $mut )? path_item.put,
+                        & $( $mut )? path_item.post,
+                        & $( $mut )? path_item.delete,
+                        & $( $mut )? path_item.options,
+                        & $( $mut )? path_item.head,
+                        & $( $mut )? path_item.patch,
+                        & $( $mut )? path_item.trace,
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .for_each(|operation| self::operation(operation, v));
+                }
+            }
+
+            pub(in crate::openapi) fn ref_or_schema<V: Visit>(
+                schema: & $( $mut )? RefOr<Schema>,
+                v: &mut V,
+            ) {
+                match schema {
+                    RefOr::T(schema) => self::schema(schema, v),
+                    RefOr::Ref(reference) => v.visit_ref(reference),
+                }
+            }
+
+            fn schema<V: Visit>(schema: & $( $mut )? Schema, v: &mut V) {
+                v.visit_schema(schema);
+
+                match schema {
+                    Schema::Array(array) => {
+                        if let ArrayItems::RefOrSchema(items) = & $( $mut )? array.items {
+                            ref_or_schema(items, v);
+                        }
+                        array
+                            .prefix_items
+                            .$iter()
+                            .for_each(|item| self::schema(item, v));
+                    }
+                    Schema::Object(object) => {
+                        object
+                            .properties
+                            .$values()
+                            .for_each(|property| ref_or_schema(property, v));
+                        if let Some(AdditionalProperties::RefOr(additional_properties)) =
+                            object.additional_properties.$as_deref()
+                        {
+                            ref_or_schema(additional_properties, v);
+                        }
+                        if let Some(property_names) = object.property_names.$as_deref() {
+                            self::schema(property_names, v);
+                        }
+                    }
+                    Schema::OneOf(one_of) => one_of
+                        .items
+                        .$iter()
+                        .for_each(|item| ref_or_schema(item, v)),
+                    Schema::AllOf(all_of) => all_of
+                        .items
+                        .$iter()
+                        .for_each(|item| ref_or_schema(item, v)),
+                    Schema::AnyOf(any_of) => any_of
+                        .items
+                        .$iter()
+                        .for_each(|item| ref_or_schema(item, v)),
+                }
+            }
+
+            fn parameter<V: Visit>(parameter: & $( $mut )? Parameter, v: &mut V) {
+                if let Some(schema) = parameter.schema.$as_ref() {
+                    ref_or_schema(schema, v);
+                }
+            }
+
+            fn ref_or_parameter<V: Visit>(parameter: & $( $mut )? RefOr<Parameter>, v: &mut V) {
+                match parameter {
+                    RefOr::T(parameter) => self::parameter(parameter, v),
+                    RefOr::Ref(reference) => v.visit_ref(reference),
+                }
+            }
+
+            fn ref_or_request_body<V: Visit>(
+                request_body: & $( $mut )? RefOr<RequestBody>,
+                v: &mut V,
+            ) {
+                match request_body {
+                    RefOr::T(request_body) => request_body
+                        .content
+                        .$values()
+                        .for_each(|content| self::content(content, v)),
+                    RefOr::Ref(reference) => v.visit_ref(reference),
+                }
+            }
+
+            fn operation<V: Visit>(operation: & $( $mut )? Operation, v: &mut V) {
+                operation
+                    .parameters
+                    .$iter()
+                    .flatten()
+                    .for_each(|parameter| ref_or_parameter(parameter, v));
+                if let Some(request_body) = operation.request_body.$as_ref() {
+                    ref_or_request_body(request_body, v);
+                }
+                operation
+                    .responses
+                    .responses
+                    .$values()
+                    .for_each(|response| ref_or_response(response, v));
+            }
+
+            pub(in crate::openapi) fn ref_or_response<V: Visit>(
+                response: & $( $mut )? RefOr<Response>,
+                v: &mut V,
+            ) {
+                match response {
+                    RefOr::T(response) => {
+                        response
+                            .headers
+                            .$values()
+                            .for_each(|header| ref_or_schema(& $( $mut )? header.schema, v));
+                        response
+                            .content
+                            .$values()
+                            .for_each(|content| self::content(content, v));
+                    }
+                    RefOr::Ref(reference) => v.visit_ref(reference),
+                }
+            }
+
+            fn content<V: Visit>(content: & $( $mut )? Content, v: &mut V) {
+                if let Some(schema) = content.schema.$as_ref() {
+                    ref_or_schema(schema, v);
+                }
+            }
+        };
+    }
+
+    walk!([mut] iter_mut, values_mut, as_mut, as_deref_mut);
+
+    /// Visit only [`Schema`]s with given closure.
+    pub(super) struct SchemaVisitor<F>(pub(super) F);
+
+    impl<F: FnMut(&mut Schema)> Visit for SchemaVisitor<F> {
+        fn visit_schema(&mut self, schema: &mut Schema) {
+            (self.0)(schema)
+        }
+    }
+
+    /// Visit only [`Ref`]s with given closure.
+    pub(super) struct RefVisitor<F>(pub(super) F);
+
+    impl<F: FnMut(&mut Ref)> Visit for RefVisitor<F> {
+        fn visit_ref(&mut self, reference: &mut Ref) {
+            (self.0)(reference)
+        }
+    }
+
+    /// Read only walk through the [`OpenApi`].
+    pub(super) mod shared {
+        use super::{
+            AdditionalProperties, ArrayItems, Content, OpenApi, Operation, Parameter, Ref, RefOr,
+            RequestBody, Response, Schema,
+        };
+
+        walk!([] iter, values, as_ref, as_deref);
+
+        /// Visit only [`Ref`]s with given closure.
+        pub(in crate::openapi) struct RefVisitor<F>(pub(in crate::openapi) F);
+
+        impl<F: FnMut(&Ref)> Visit for RefVisitor<F> {
+            fn visit_ref(&mut self, reference: &Ref) {
+                (self.0)(reference)
+            }
+        }
+    }
+}
+
 impl OpenApiBuilder {
     /// Add [`Info`] metadata of the API.
     pub fn info<I: Into<Info>>(mut self, info: I) -> Self {
@@ -670,6 +1358,616 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn write_json_to_writer_round_trip_success() {
+        let openapi = OpenApi::new(Info::new("pet api", "0.1.0"), Paths::new());
+
+        let mut json = Vec::new();
+        openapi.write_json(&mut json).unwrap();
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&json).unwrap(),
+            serde_json::to_value(&openapi).unwrap()
+        );
+
+        let mut pretty_json = Vec::new();
+        openapi.write_pretty_json(&mut pretty_json).unwrap();
+        assert_eq!(
+            String::from_utf8(pretty_json).unwrap(),
+            openapi.to_pretty_json().unwrap()
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn write_yaml_to_writer_success() {
+        let openapi = OpenApi::new(Info::new("pet api", "0.1.0"), Paths::new());
+
+        let mut yaml = Vec::new();
+        openapi.write_yaml(&mut yaml).unwrap();
+        assert_eq!(String::from_utf8(yaml).unwrap(), openapi.to_yaml().unwrap());
+    }
+
+    #[test]
+    fn serialize_minimal_openapi_omits_empty_fields() {
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("pet api", "0.1.0"))
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new()
+                            .operation_id(Some("get_pets"))
+                            .external_docs(Some(ExternalDocs::new("https://example.com")))
+                            .response("200", Response::new("Pets")),
+                    ),
+                ),
+            )
+            .components(Some(Components::new()))
+            .build();
+        let value = serde_json::to_value(&openapi).unwrap();
+
+        assert!(value.pointer("/info/description").is_none());
+        assert!(value.pointer("/info/contact").is_none());
+        assert!(value.pointer("/servers").is_none());
+        assert!(value.pointer("/tags").is_none());
+        assert!(value.pointer("/paths/~1pets/parameters").is_none());
+        assert!(value.pointer("/paths/~1pets/get/parameters").is_none());
+        assert!(value.pointer("/paths/~1pets/get/security").is_none());
+        assert!(value
+            .pointer("/paths/~1pets/get/externalDocs/description")
+            .is_none());
+        assert!(value
+            .pointer("/paths/~1pets/get/responses/200/content")
+            .is_none());
+        assert!(value
+            .pointer("/paths/~1pets/get/responses/200/headers")
+            .is_none());
+        assert!(value.pointer("/components/schemas").is_none());
+        assert!(value.pointer("/components/securitySchemes").is_none());
+    }
+
+    #[test]
+    fn openapi_replace_schema_in_modify() {
+        struct RuntimeSchemas;
+
+        impl crate::Modify for RuntimeSchemas {
+            fn modify(&self, openapi: &mut OpenApi) {
+                openapi.replace_schema("Pet", Object::with_type(Type::Object));
+            }
+        }
+
+        let mut openapi = OpenApi::new(Info::new("pet api", "0.1.0"), Paths::new());
+        crate::Modify::modify(&RuntimeSchemas, &mut openapi);
+        assert_eq!(
+            openapi.components.as_ref().unwrap().schemas.get("Pet"),
+            Some(&RefOr::T(Schema::Object(Object::with_type(Type::Object))))
+        );
+
+        openapi.replace_schema("Pet", Object::with_type(Type::String));
+        assert_eq!(
+            openapi.components.as_ref().unwrap().schemas.get("Pet"),
+            Some(&RefOr::T(Schema::Object(Object::with_type(Type::String))))
+        );
+    }
+
+    #[test]
+    fn openapi_walk_schemas_adds_title_to_every_schema() {
+        use crate::openapi::{
+            path::ParameterBuilder, request_body::RequestBodyBuilder, ArrayBuilder, ObjectBuilder,
+        };
+
+        let mut openapi = OpenApiBuilder::new()
+            .info(Info::new("pet api", "0.1.0"))
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets/{id}",
+                    PathItem::new(
+                        HttpMethod::Post,
+                        OperationBuilder::new()
+                            .operation_id(Some("update_pet"))
+                            .parameter(
+                                ParameterBuilder::new()
+                                    .name("id")
+                                    .schema(Some(Object::with_type(Type::Integer))),
+                            )
+                            .request_body(Some(
+                                RequestBodyBuilder::new()
+                                    .content(
+                                        "application/json",
+                                        Content::new(Some(Ref::from_schema_name("Pet"))),
+                                    )
+                                    .build(),
+                            ))
+                            .response(
+                                "200",
+                                Response::builder().description("Pets").content(
+                                    "application/json",
+                                    Content::new(Some(
+                                        ArrayBuilder::new().items(Ref::from_schema_name("Pet")),
+                                    )),
+                                ),
+                            ),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property("name", Object::with_type(Type::String))
+                            .property("friend", Ref::from_schema_name("Pet"))
+                            .property(
+                                "tags",
+                                ArrayBuilder::new().items(Object::with_type(Type::String)),
+                            ),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let mut visited = 0;
+        openapi.walk_schemas(|schema| {
+            visited += 1;
+            let title = Some(format!("Schema{visited}"));
+            match schema {
+                Schema::Object(object) => object.title = title,
+                Schema::Array(array) => array.title = title,
+                Schema::OneOf(one_of) => one_of.title = title,
+                Schema::AllOf(all_of) => all_of.title = title,
+                _ => (),
+            }
+        });
+
+        assert_eq!(visited, 6);
+        let value = serde_json::to_value(&openapi).unwrap();
+        for pointer in [
+            "/components/schemas/Pet/title",
+            "/components/schemas/Pet/properties/name/title",
+            "/components/schemas/Pet/properties/tags/title",
+            "/components/schemas/Pet/properties/tags/items/title",
+            "/paths/~1pets~1{id}/post/parameters/0/schema/title",
+            "/paths/~1pets~1{id}/post/responses/200/content/application~1json/schema/title",
+        ] {
+            assert!(
+                value.pointer(pointer).is_some(),
+                "{pointer} should have title"
+            );
+        }
+        assert!(value
+            .pointer("/components/schemas/Pet/properties/friend/title")
+            .is_none());
+        assert!(value
+            .pointer("/paths/~1pets~1{id}/post/requestBody/content/application~1json/schema/title")
+            .is_none());
+    }
+
+    #[test]
+    fn openapi_semantically_eq_ignores_map_order() {
+        use crate::openapi::ObjectBuilder;
+
+        let pet = |properties: [&str; 2]| {
+            properties
+                .into_iter()
+                .fold(ObjectBuilder::new(), |object, property| {
+                    object.property(property, Object::with_type(Type::String))
+                })
+        };
+        let response = |content_types: [&str; 2]| {
+            content_types.into_iter().fold(
+                Response::builder().description("Pet"),
+                |response, content_type| {
+                    response.content(
+                        content_type,
+                        Content::new(Some(Ref::from_schema_name("Pet"))),
+                    )
+                },
+            )
+        };
+        let openapi = |content_types: [&str; 2], properties: [&str; 2]| {
+            OpenApiBuilder::new()
+                .info(Info::new("pet api", "0.1.0"))
+                .paths(
+                    PathsBuilder::new().path(
+                        "/pets",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new()
+                                .operation_id(Some("get_pet"))
+                                .response("200", response(content_types)),
+                        ),
+                    ),
+                )
+                .components(Some(
+                    ComponentsBuilder::new()
+                        .schema("Pet", pet(properties))
+                        .build(),
+                ))
+                .build()
+        };
+
+        let first = openapi(["application/json", "application/xml"], ["name", "breed"]);
+        let second = openapi(["application/xml", "application/json"], ["breed", "name"]);
+        let other = openapi(["application/json", "application/xml"], ["name", "age"]);
+
+        assert!(first.semantically_eq(&second));
+        assert!(!first.semantically_eq(&other));
+    }
+
+    #[test]
+    fn openapi_validate_reports_all_problems() {
+        use crate::openapi::{
+            path::{ParameterBuilder, ParameterIn},
+            validation::ValidationError,
+        };
+
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("pet api", "0.1.0"))
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets/{id}",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new()
+                                .operation_id(Some("get_pet"))
+                                .parameter(ParameterBuilder::new().name("pet_id"))
+                                .parameter(
+                                    ParameterBuilder::new()
+                                        .name("limit")
+                                        .parameter_in(ParameterIn::Query)
+                                        .schema(Some(Object::with_type(Type::Integer)))
+                                        .example(Some(serde_json::json!("ten"))),
+                                )
+                                .response(
+                                    "200",
+                                    Response::builder().description("Pet").content(
+                                        "application/json",
+                                        Content::new(Some(Ref::from_schema_name("Pet"))),
+                                    ),
+                                ),
+                        ),
+                    )
+                    .path(
+                        "/owners",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new().operation_id(Some("get_pet")),
+                        ),
+                    ),
+            )
+            .build();
+
+        let errors = openapi.validate().expect_err("document should be invalid");
+
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError::DanglingRef {
+                    reference: "#/components/schemas/Pet".to_string()
+                },
+                ValidationError::DuplicateOperationId {
+                    operation_id: "get_pet_get".to_string()
+                },
+                ValidationError::UnknownPathParameter {
+                    path: "/pets/{id}".to_string(),
+                    name: "pet_id".to_string()
+                },
+                ValidationError::MissingPathParameter {
+                    path: "/pets/{id}".to_string(),
+                    name: "id".to_string()
+                },
+                ValidationError::ExampleTypeMismatch {
+                    path: "/pets/{id}".to_string(),
+                    name: "limit".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn openapi_validate_ignores_ref_keys_in_example_and_extension_values() {
+        use crate::openapi::{extensions::ExtensionsBuilder, schema::ObjectBuilder};
+
+        let pet = ObjectBuilder::new()
+            .property(
+                "owner",
+                ObjectBuilder::new()
+                    .schema_type(Type::Object)
+                    .examples([serde_json::json!({ "$ref": "#/not/a/schema" })]),
+            )
+            .extensions(Some(
+                ExtensionsBuilder::new()
+                    .add("x-source", serde_json::json!({ "$ref": "#/missing" }))
+                    .build(),
+            ))
+            .build();
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("pet api", "0.1.0"))
+            .components(Some(ComponentsBuilder::new().schema("Pet", pet).build()))
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new()
+                            .operation_id(Some("get_pets"))
+                            .response(
+                                "200",
+                                Response::builder().description("Pets").content(
+                                    "application/json",
+                                    Content::new(Some(Ref::from_schema_name("Pet"))),
+                                ),
+                            )
+                            .response(
+                                "404",
+                                Response::builder().description("Not found").content(
+                                    "application/json",
+                                    Content::new(Some(Ref::from_schema_name("NotFound"))),
+                                ),
+                            )
+                            .response(
+                                "409",
+                                Response::builder().description("Conflict").content(
+                                    "application/json",
+                                    Content::new(Some(Ref::new(
+                                        "#/components/schemas/Pet/properties/owner",
+                                    ))),
+                                ),
+                            )
+                            .response(
+                                "410",
+                                Response::builder().description("Gone").content(
+                                    "application/json",
+                                    Content::new(Some(Ref::new(
+                                        "#/components/schemas/Pet/properties/name",
+                                    ))),
+                                ),
+                            ),
+                    ),
+                ),
+            )
+            .build();
+
+        let errors = openapi.validate().expect_err("document should be invalid");
+
+        assert_eq!(
+            errors,
+            vec![
+                validation::ValidationError::DanglingRef {
+                    reference: "#/components/schemas/NotFound".to_string()
+                },
+                validation::ValidationError::DanglingRef {
+                    reference: "#/components/schemas/Pet/properties/name".to_string()
+                },
+            ]
+        );
+        let error: &dyn std::error::Error = &errors[0];
+        assert_eq!(
+            error.to_string(),
+            "reference `#/components/schemas/NotFound` does not resolve within the document"
+        );
+    }
+
+    #[test]
+    fn openapi_add_response_to_all_keeps_existing_responses() {
+        use crate::openapi::path::OperationBuilder;
+
+        let operation = |operation_id: &str| {
+            OperationBuilder::new()
+                .operation_id(Some(operation_id))
+                .response("200", Response::new("Success"))
+        };
+        let mut openapi = OpenApi::new(
+            Info::new("pet api", "0.1.0"),
+            PathsBuilder::new()
+                .path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        operation("get_pets").response("500", Response::new("Pet store is down")),
+                    ),
+                )
+                .path(
+                    "/pets",
+                    PathItem::new(HttpMethod::Post, operation("create_pet")),
+                )
+                .path(
+                    "/owners",
+                    PathItem::new(HttpMethod::Get, operation("get_owners")),
+                ),
+        );
+
+        openapi.add_response_to_all("500", Response::new("Internal server error"));
+
+        let mut descriptions = Vec::new();
+        openapi.for_each_operation(|path, method, operation| {
+            let description = match operation.responses.responses.get("500") {
+                Some(RefOr::T(response)) => response.description.clone(),
+                _ => String::new(),
+            };
+            descriptions.push((path.to_string(), method.to_string(), description));
+            assert!(operation.responses.responses.contains_key("200"));
+        });
+        descriptions.sort();
+
+        assert_eq!(
+            descriptions,
+            vec![
+                (
+                    "/owners".to_string(),
+                    "GET".to_string(),
+                    "Internal server error".to_string()
+                ),
+                (
+                    "/pets".to_string(),
+                    "GET".to_string(),
+                    "Pet store is down".to_string()
+                ),
+                (
+                    "/pets".to_string(),
+                    "POST".to_string(),
+                    "Internal server error".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn openapi_wrap_response_bodies_nests_body_under_key() {
+        use crate::openapi::{content::ContentBuilder, path::OperationBuilder};
+
+        let mut openapi = OpenApi::new(
+            Info::new("pet api", "0.1.0"),
+            PathsBuilder::new().path(
+                "/pets",
+                PathItem::new(
+                    HttpMethod::Get,
+                    OperationBuilder::new()
+                        .operation_id(Some("get_pets"))
+                        .response(
+                            "200",
+                            ResponseBuilder::new().description("Pets").content(
+                                "application/json",
+                                ContentBuilder::new()
+                                    .schema(Some(Ref::from_schema_name("Pet")))
+                                    .build(),
+                            ),
+                        )
+                        .response(
+                            "201",
+                            ResponseBuilder::new().description("Pet").content(
+                                "application/vnd.pet+json; charset=utf-8",
+                                ContentBuilder::new()
+                                    .schema(Some(Ref::from_schema_name("Pet")))
+                                    .build(),
+                            ),
+                        )
+                        .response(
+                            "203",
+                            ResponseBuilder::new().description("Pet name").content(
+                                "text/plain",
+                                ContentBuilder::new()
+                                    .schema(Some(ObjectBuilder::new().schema_type(Type::String)))
+                                    .build(),
+                            ),
+                        )
+                        .response("404", Ref::from_response_name("NotFound")),
+                ),
+            ),
+        );
+
+        openapi.wrap_response_bodies("data");
+
+        let value = serde_json::to_value(&openapi).expect("OpenApi should serialize");
+        let responses = value
+            .pointer("/paths/~1pets/get/responses")
+            .expect("responses should exist");
+        assert_eq!(
+            responses.pointer("/200/content/application~1json/schema"),
+            Some(&serde_json::json!({
+                "type": "object",
+                "required": ["data"],
+                "properties": {
+                    "data": { "$ref": "#/components/schemas/Pet" }
+                }
+            }))
+        );
+        assert_eq!(
+            responses
+                .pointer("/201/content/application~1vnd.pet+json; charset=utf-8/schema/required"),
+            Some(&serde_json::json!(["data"]))
+        );
+        assert_eq!(
+            responses.pointer("/203/content/text~1plain/schema"),
+            Some(&serde_json::json!({ "type": "string" }))
+        );
+        assert_eq!(
+            responses.pointer("/404"),
+            Some(&serde_json::json!({ "$ref": "#/components/responses/NotFound" }))
+        );
+    }
+
+    #[test]
+    fn openapi_security_schemes_delegates_to_components() {
+        use crate::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+
+        let mut openapi = OpenApi::new(Info::new("pet api", "0.1.0"), Paths::new());
+        assert_eq!(openapi.security_schemes().count(), 0);
+
+        let scheme = SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("x-api-key")));
+        openapi.components = Some(
+            ComponentsBuilder::new()
+                .security_scheme("api_key", scheme.clone())
+                .build(),
+        );
+
+        assert_eq!(
+            openapi.security_schemes().collect::<Vec<_>>(),
+            vec![(&"api_key".to_string(), &scheme)]
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn deserialize_openapi_yaml_round_trip_success() {
+        let yaml = r#"
+openapi: 3.1.0
+info:
+  title: pet api
+  version: 0.1.0
+paths:
+  /pets/{id}:
+    get:
+      operationId: get_pet
+      parameters:
+        - name: id
+          in: path
+          required: true
+          schema:
+            type: integer
+      responses:
+        "200":
+          description: Pet found
+"#;
+        let openapi = OpenApi::from_yaml(yaml).expect("OpenApi must be parsed from YAML");
+        let value = serde_json::to_value(&openapi).expect("OpenApi must serialize to JSON");
+
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "openapi": "3.1.0",
+                "info": {
+                    "title": "pet api",
+                    "version": "0.1.0"
+                },
+                "paths": {
+                    "/pets/{id}": {
+                        "get": {
+                            "operationId": "get_pet",
+                            "parameters": [
+                                {
+                                    "name": "id",
+                                    "in": "path",
+                                    "required": true,
+                                    "schema": {
+                                        "type": "integer"
+                                    }
+                                }
+                            ],
+                            "responses": {
+                                "200": {
+                                    "description": "Pet found"
+                                }
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
     #[test]
     fn serialize_openapi_json_minimal_success() {
         let openapi = OpenApi::new(
@@ -917,4 +2215,121 @@ mod tests {
 
         assert_json_snapshot!(api);
     }
+
+    #[test]
+    fn openapi_get_registered_tag_by_name() {
+        let api = OpenApiBuilder::new()
+            .tags(Some([
+                Tag::new("pet"),
+                tag::TagBuilder::new()
+                    .name("store")
+                    .description(Some("Store operations"))
+                    .build(),
+            ]))
+            .build();
+
+        assert_eq!(api.tags().len(), 2);
+        let store = api
+            .get_tag("store")
+            .expect("store tag should be registered");
+        assert_eq!(store.name, "store");
+        assert_eq!(store.description.as_deref(), Some("Store operations"));
+        assert!(api.get_tag("user").is_none());
+        assert!(OpenApiBuilder::new().build().tags().is_empty());
+    }
+
+    #[test]
+    fn openapi_rename_component_rewrites_refs() {
+        let mut api = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new()
+                            .operation_id(Some("get_pets"))
+                            .response(
+                                "200",
+                                response::ResponseBuilder::new()
+                                    .description("Pets")
+                                    .content(
+                                        "application/json",
+                                        Content::new(Some(
+                                            schema::ArrayBuilder::new()
+                                                .items(Ref::from_schema_name("Pet")),
+                                        )),
+                                    ),
+                            ),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("Pet", ObjectBuilder::new().property("name", Object::new()))
+                    .schema(
+                        "Owner",
+                        ObjectBuilder::new()
+                            .property("pet", Ref::from_schema_name("Pet"))
+                            .property(
+                                "pets",
+                                schema::ArrayBuilder::new().items(Ref::from_schema_name("Pet")),
+                            ),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        assert!(api.rename_component("Pet", "Animal"));
+
+        let schemas = &api
+            .components
+            .as_ref()
+            .expect("components should exist")
+            .schemas;
+        assert!(schemas.contains_key("Animal"));
+        assert!(!schemas.contains_key("Pet"));
+
+        let value = serde_json::to_value(&api).expect("should serialize as json");
+        let animal = serde_json::json!({"$ref": "#/components/schemas/Animal"});
+        assert_eq!(
+            value.pointer("/components/schemas/Owner/properties/pet"),
+            Some(&animal)
+        );
+        assert_eq!(
+            value.pointer("/components/schemas/Owner/properties/pets/items"),
+            Some(&animal)
+        );
+        assert_eq!(
+            value.pointer("/paths/~1pets/get/responses/200/content/application~1json/schema/items"),
+            Some(&animal)
+        );
+        assert!(!value.to_string().contains("#/components/schemas/Pet"));
+    }
+
+    #[test]
+    fn openapi_rename_component_refuses_to_overwrite_existing_schema() {
+        let mut api = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("Pet", ObjectBuilder::new().property("name", Object::new()))
+                    .schema(
+                        "Animal",
+                        ObjectBuilder::new().property("kind", Object::new()),
+                    )
+                    .schema(
+                        "Owner",
+                        ObjectBuilder::new().property("pet", Ref::from_schema_name("Pet")),
+                    )
+                    .build(),
+            ))
+            .build();
+        let expected = serde_json::to_value(&api).expect("should serialize as json");
+
+        assert!(!api.rename_component("Pet", "Animal"));
+
+        assert_eq!(
+            serde_json::to_value(&api).expect("should serialize as json"),
+            expected
+        );
+    }
 }
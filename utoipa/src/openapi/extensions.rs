@@ -2,7 +2,7 @@
 //!
 //! [extensions]: https://spec.openapis.org/oas/latest.html#specification-extensions
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     ops::{Deref, DerefMut},
 };
 
@@ -17,11 +17,12 @@ builder! {
 
     /// Additional [data for extending][extensions] the OpenAPI specification.
     ///
+    /// Extensions are serialized in alphabetical order so that serialization is deterministic.
+    ///
     /// [extensions]: https://spec.openapis.org/oas/latest.html#specification-extensions
-    #[derive(Default, Serialize, Clone, PartialEq, Eq)]
+    #[derive(Default, Clone, PartialEq, Eq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     pub struct Extensions{
-        #[serde(flatten)]
         extensions: HashMap<String, serde_json::Value>,
     }
 }
@@ -65,6 +66,19 @@ impl From<Extensions> for HashMap<String, serde_json::Value> {
     }
 }
 
+impl Serialize for Extensions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        // sort keys so that serialization does not depend on the HashMap iteration order
+        self.extensions
+            .iter()
+            .collect::<BTreeMap<_, _>>()
+            .serialize(serializer)
+    }
+}
+
 impl<'de> serde::de::Deserialize<'de> for Extensions {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -127,4 +141,18 @@ mod tests {
         assert_eq!(extensions.get("x-some-extension"), Some(&expected));
         assert_eq!(extensions.get("another-extension"), Some(&expected));
     }
+
+    #[test]
+    fn extensions_serialize_in_alphabetical_order() {
+        let extensions = ExtensionsBuilder::new()
+            .add("x-c", "c")
+            .add("x-a", "a")
+            .add("x-b", "b")
+            .build();
+
+        assert_eq!(
+            serde_json::to_string(&extensions).unwrap(),
+            r#"{"x-a":"a","x-b":"b","x-c":"c"}"#
+        );
+    }
 }
@@ -18,6 +18,7 @@ builder! {
         /// Target url for external documentation location.
         pub url: String,
         /// Additional description supporting markdown syntax of the external documentation.
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
 
         /// Optional extensions "x-something".
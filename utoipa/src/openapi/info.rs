@@ -43,6 +43,10 @@ builder! {
         /// Title of the API.
         pub title: String,
 
+        /// Optional short summary of the API.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub summary: Option<String>,
+
         /// Optional description of the API.
         ///
         /// Value supports markdown syntax.
@@ -106,6 +110,11 @@ impl InfoBuilder {
         set_value!(self version version.into())
     }
 
+    /// Add short summary of the API.
+    pub fn summary<S: Into<String>>(mut self, summary: Option<S>) -> Self {
+        set_value!(self summary summary.map(|summary| summary.into()))
+    }
+
     /// Add description of the API.
     pub fn description<S: Into<String>>(mut self, description: Option<S>) -> Self {
         set_value!(self description description.map(|description| description.into()))
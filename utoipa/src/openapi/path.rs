@@ -11,7 +11,7 @@ use super::{
     request_body::RequestBody,
     response::{Response, Responses},
     security::SecurityRequirement,
-    set_value, Deprecated, ExternalDocs, RefOr, Required, Schema, Server,
+    set_value, Deprecated, ExternalDocs, Ref, RefOr, Required, Schema, Server,
 };
 
 #[cfg(not(feature = "preserve_path_order"))]
@@ -68,6 +68,43 @@ impl Paths {
         self.paths.get(path.as_ref())
     }
 
+    /// Return _`Option`_ of reference to [`PathItem`] by given relative path _`P`_ or `None` if
+    /// not found. Shorthand for [`Paths::get_path_item`].
+    pub fn get<P: AsRef<str>>(&self, path: P) -> Option<&PathItem> {
+        self.get_path_item(path)
+    }
+
+    /// Return _`Option`_ of mutable reference to [`PathItem`] by given relative path _`P`_ or
+    /// `None` if not found.
+    ///
+    /// # Examples
+    ///
+    /// _**Deprecate get operation of user path.**_
+    /// ```rust
+    /// # use utoipa::openapi::path::{HttpMethod, OperationBuilder, PathItem, PathsBuilder};
+    /// # use utoipa::openapi::Deprecated;
+    /// let mut paths = PathsBuilder::new()
+    ///     .path(
+    ///         "/api/v1/user",
+    ///         PathItem::new(HttpMethod::Get, OperationBuilder::new().operation_id(Some("get_user"))),
+    ///     )
+    ///     .build();
+    ///
+    /// if let Some(path_item) = paths.get_mut("/api/v1/user") {
+    ///     path_item.get.as_mut().unwrap().deprecated = Some(Deprecated::True);
+    /// }
+    /// ```
+    pub fn get_mut<P: AsRef<str>>(&mut self, path: P) -> Option<&mut PathItem> {
+        self.paths.get_mut(path.as_ref())
+    }
+
+    /// Iterate over relative paths and their [`PathItem`]s in the order of [`Paths::paths`] map.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &PathItem)> {
+        self.paths
+            .iter()
+            .map(|(path, path_item)| (path.as_str(), path_item))
+    }
+
     /// Return _`Option`_ of reference to [`Operation`] from map of paths or `None` if not found.
     ///
     /// * First will try to find [`PathItem`] by given relative path _`P`_ e.g. `"/api/v1/user"`.
@@ -153,6 +190,50 @@ impl Paths {
             paths_extensions.merge(other_paths_extensions);
         }
     }
+
+    /// Prepend _`prefix`_ to every path in [`Paths::paths`] map.
+    ///
+    /// Leading and trailing slashes are normalized so that joining `/api/v1/` with `/pets`
+    /// results `/api/v1/pets`. The root path `/` becomes `/api/v1`.
+    ///
+    /// If multiple paths end up to the same prefixed path e.g. `""` and `"/"` the [`Operation`]s
+    /// of the [`PathItem`]s are merged the same way as in [`PathsBuilder::path`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::path::{HttpMethod, OperationBuilder, PathItem, PathsBuilder};
+    /// let mut paths = PathsBuilder::new()
+    ///     .path(
+    ///         "/pets",
+    ///         PathItem::new(HttpMethod::Get, OperationBuilder::new().operation_id(Some("get_pets"))),
+    ///     )
+    ///     .build();
+    ///
+    /// paths.prefix("/api/v1/");
+    ///
+    /// assert!(paths.get_path_item("/api/v1/pets").is_some());
+    /// ```
+    pub fn prefix<P: AsRef<str>>(&mut self, prefix: P) {
+        let prefix = prefix.as_ref().trim_matches('/');
+        if prefix.is_empty() {
+            return;
+        }
+
+        for (path, item) in std::mem::take(&mut self.paths) {
+            let path = match path.trim_start_matches('/') {
+                // root path becomes the prefix itself without trailing slash
+                "" => format!("/{prefix}"),
+                path => format!("/{prefix}/{path}"),
+            };
+
+            if let Some(existing_item) = self.paths.get_mut(&path) {
+                existing_item.merge_operations(item);
+            } else {
+                self.paths.insert(path, item);
+            }
+        }
+    }
 }
 
 impl PathsBuilder {
@@ -371,6 +452,31 @@ impl PathItem {
         path_item
     }
 
+    /// Remove `example` and `examples` from all [`Response`]s of all [`Operation`]s in this
+    /// [`PathItem`]. See [`Response::clear_examples`] for more details.
+    ///
+    /// Responses defined as references are left untouched.
+    pub fn clear_response_examples(&mut self) {
+        [
+            &mut self.get,
+            &mut self.put,
+            &mut self.post,
+            &mut self.delete,
+            &mut self.options,
+            &mut self.head,
+            &mut self.patch,
+            &mut self.trace,
+        ]
+        .into_iter()
+        .flatten()
+        .flat_map(|operation| operation.responses.responses.values_mut())
+        .for_each(|response| {
+            if let RefOr::T(response) = response {
+                response.clear_examples();
+            }
+        });
+    }
+
     /// Merge all defined [`Operation`]s from given [`PathItem`] to `self` if `self` does not have
     /// existing operation.
     pub fn merge_operations(&mut self, path_item: PathItem) {
@@ -467,6 +573,71 @@ pub enum HttpMethod {
     Trace,
 }
 
+impl HttpMethod {
+    /// Get upper case name of the [`HttpMethod`] e.g. `GET`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+            Self::Put => "PUT",
+            Self::Delete => "DELETE",
+            Self::Options => "OPTIONS",
+            Self::Head => "HEAD",
+            Self::Patch => "PATCH",
+            Self::Trace => "TRACE",
+        }
+    }
+}
+
+impl std::fmt::Display for HttpMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for HttpMethod {
+    type Err = ParseHttpMethodError;
+
+    /// Parse [`HttpMethod`] from case insensitive method name e.g. `GET` or `post`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::HttpMethod;
+    /// let method = "post".parse::<HttpMethod>().unwrap();
+    ///
+    /// assert!(method == HttpMethod::Post);
+    /// assert_eq!(method.to_string(), "POST");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Self::Get,
+            Self::Post,
+            Self::Put,
+            Self::Delete,
+            Self::Options,
+            Self::Head,
+            Self::Patch,
+            Self::Trace,
+        ]
+        .into_iter()
+        .find(|method| method.as_str().eq_ignore_ascii_case(s))
+        .ok_or_else(|| ParseHttpMethodError(s.to_string()))
+    }
+}
+
+/// Error returned when [`HttpMethod`] cannot be parsed from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseHttpMethodError(String);
+
+impl std::fmt::Display for ParseHttpMethodError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown http method: `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseHttpMethodError {}
+
 builder! {
     OperationBuilder;
 
@@ -521,13 +692,15 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub external_docs: Option<ExternalDocs>,
 
-        /// List of applicable parameters for this [`Operation`].
+        /// List of applicable parameters for this [`Operation`]. Parameters can be defined inline
+        /// or referenced from [`Components`][crate::openapi::Components] parameters.
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub parameters: Option<Vec<Parameter>>,
+        pub parameters: Option<Vec<RefOr<Parameter>>>,
 
-        /// Optional request body for this [`Operation`].
+        /// Optional request body for this [`Operation`]. Request body can be defined inline or
+        /// referenced from [`Components`][crate::openapi::Components] request bodies.
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub request_body: Option<RequestBody>,
+        pub request_body: Option<RefOr<RequestBody>>,
 
         /// List of possible responses returned by the [`Operation`].
         pub responses: Responses,
@@ -595,13 +768,18 @@ impl OperationBuilder {
         set_value!(self description description.map(|description| description.into()))
     }
 
+    /// Add or change external docs of the [`Operation`].
+    pub fn external_docs(mut self, external_docs: Option<ExternalDocs>) -> Self {
+        set_value!(self external_docs external_docs)
+    }
+
     /// Add or change operation id of the [`Operation`].
     pub fn operation_id<S: Into<String>>(mut self, operation_id: Option<S>) -> Self {
         set_value!(self operation_id operation_id.map(|operation_id| operation_id.into()))
     }
 
     /// Add or change parameters of the [`Operation`].
-    pub fn parameters<I: IntoIterator<Item = P>, P: Into<Parameter>>(
+    pub fn parameters<I: IntoIterator<Item = P>, P: Into<RefOr<Parameter>>>(
         mut self,
         parameters: Option<I>,
     ) -> Self {
@@ -621,7 +799,7 @@ impl OperationBuilder {
     }
 
     /// Append parameter to [`Operation`] parameters.
-    pub fn parameter<P: Into<Parameter>>(mut self, parameter: P) -> Self {
+    pub fn parameter<P: Into<RefOr<Parameter>>>(mut self, parameter: P) -> Self {
         match self.parameters {
             Some(ref mut parameters) => parameters.push(parameter.into()),
             None => {
@@ -633,8 +811,8 @@ impl OperationBuilder {
     }
 
     /// Add or change request body of the [`Operation`].
-    pub fn request_body(mut self, request_body: Option<RequestBody>) -> Self {
-        set_value!(self request_body request_body)
+    pub fn request_body<R: Into<RefOr<RequestBody>>>(mut self, request_body: Option<R>) -> Self {
+        set_value!(self request_body request_body.map(|request_body| request_body.into()))
     }
 
     /// Add or change responses of the [`Operation`].
@@ -771,7 +949,7 @@ builder! {
         /// Example of [`Parameter`]'s potential value. This examples will override example
         /// within [`Parameter::schema`] if defined.
         #[serde(skip_serializing_if = "Option::is_none")]
-        example: Option<Value>,
+        pub example: Option<Value>,
 
         /// Optional extensions "x-something".
         #[serde(skip_serializing_if = "Option::is_none", flatten)]
@@ -854,6 +1032,18 @@ impl ParameterBuilder {
     }
 }
 
+impl From<ParameterBuilder> for RefOr<Parameter> {
+    fn from(builder: ParameterBuilder) -> Self {
+        Self::T(builder.build())
+    }
+}
+
+impl From<Ref> for RefOr<Parameter> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
+}
+
 /// In definition of [`Parameter`].
 #[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "lowercase")]
@@ -908,8 +1098,11 @@ pub enum ParameterStyle {
 
 #[cfg(test)]
 mod tests {
-    use super::{HttpMethod, Operation, OperationBuilder};
-    use crate::openapi::{security::SecurityRequirement, server::Server, PathItem, PathsBuilder};
+    use super::{HttpMethod, Operation, OperationBuilder, ParameterBuilder, ParameterIn};
+    use crate::openapi::{
+        security::SecurityRequirement, server::Server, Content, PathItem, PathsBuilder, Ref, RefOr,
+        ResponseBuilder,
+    };
 
     #[test]
     fn test_path_order() {
@@ -1038,6 +1231,83 @@ mod tests {
         assert!(operation.security.is_some());
     }
 
+    #[test]
+    fn operation_serialize_empty_security() {
+        let operation = OperationBuilder::new()
+            .securities(Some(Vec::<SecurityRequirement>::new()))
+            .build();
+        let value = serde_json::to_value(&operation).unwrap();
+
+        assert_eq!(value.get("security"), Some(&serde_json::json!([])));
+
+        let operation = OperationBuilder::new().build();
+        let value = serde_json::to_value(&operation).unwrap();
+
+        assert!(value.get("security").is_none());
+    }
+
+    #[test]
+    fn operation_serialize_referenced_parameters_and_request_body() {
+        let operation = OperationBuilder::new()
+            .parameter(Ref::from_parameter_name("limit"))
+            .parameter(
+                ParameterBuilder::new()
+                    .name("id")
+                    .parameter_in(ParameterIn::Path),
+            )
+            .request_body(Some(Ref::from_request_body_name("Pet")))
+            .build();
+        let value = serde_json::to_value(&operation).unwrap();
+
+        assert_eq!(
+            value.get("parameters"),
+            Some(&serde_json::json!([
+                { "$ref": "#/components/parameters/limit" },
+                { "name": "id", "in": "path", "required": false }
+            ]))
+        );
+        assert_eq!(
+            value.get("requestBody"),
+            Some(&serde_json::json!({ "$ref": "#/components/requestBodies/Pet" }))
+        );
+    }
+
+    #[test]
+    fn path_item_clear_response_examples() {
+        let mut content = Content::new(Some(Ref::from_schema_name("Pet")));
+        content.example = Some(serde_json::json!({ "name": "doggie" }));
+        let response = ResponseBuilder::new()
+            .description("Pet found")
+            .content("application/json", content)
+            .build();
+        let mut path_item = PathItem {
+            get: Some(
+                OperationBuilder::new()
+                    .response("200", response.clone())
+                    .response("404", Ref::from_response_name("NotFound"))
+                    .build(),
+            ),
+            post: Some(OperationBuilder::new().response("201", response).build()),
+            ..Default::default()
+        };
+
+        path_item.clear_response_examples();
+
+        for operation in [&path_item.get, &path_item.post].into_iter().flatten() {
+            for response in operation.responses.responses.values() {
+                if let RefOr::T(response) = response {
+                    let content = response.content.get("application/json").unwrap();
+                    assert!(content.example.is_none());
+                    assert!(content.schema.is_some());
+                }
+            }
+        }
+        assert_eq!(
+            path_item.get.unwrap().responses.responses.get("404"),
+            Some(&RefOr::Ref(Ref::from_response_name("NotFound")))
+        );
+    }
+
     #[test]
     fn operation_builder_server() {
         let server1 = Server::new("/api");
@@ -1049,4 +1319,124 @@ mod tests {
 
         assert!(operation.servers.is_some());
     }
+
+    #[test]
+    fn http_method_from_str_and_display_round_trip() {
+        let get = "GET".parse::<HttpMethod>().expect("GET should parse");
+        let post = "post".parse::<HttpMethod>().expect("post should parse");
+
+        assert!(get == HttpMethod::Get);
+        assert!(post == HttpMethod::Post);
+        assert_eq!(get.to_string(), "GET");
+        assert_eq!(post.to_string(), "POST");
+        assert!(post.to_string().parse::<HttpMethod>() == Ok(HttpMethod::Post));
+        assert_eq!(
+            "connect"
+                .parse::<HttpMethod>()
+                .err()
+                .map(|error| error.to_string()),
+            Some("unknown http method: `connect`".to_string())
+        );
+    }
+
+    #[test]
+    fn paths_prefix_root_path_has_no_trailing_slash() {
+        let mut paths = PathsBuilder::new()
+            .path(
+                "/",
+                PathItem::new(
+                    HttpMethod::Get,
+                    OperationBuilder::new().operation_id(Some("index")),
+                ),
+            )
+            .path(
+                "/pets",
+                PathItem::new(
+                    HttpMethod::Get,
+                    OperationBuilder::new().operation_id(Some("pets")),
+                ),
+            )
+            .build();
+
+        paths.prefix("/api/v1/");
+
+        let mut prefixed = paths.iter().map(|(path, _)| path).collect::<Vec<_>>();
+        prefixed.sort_unstable();
+        assert_eq!(prefixed, ["/api/v1", "/api/v1/pets"]);
+    }
+
+    #[test]
+    fn paths_prefix_merges_colliding_path_items() {
+        let mut paths = PathsBuilder::new()
+            .path(
+                "",
+                PathItem::new(
+                    HttpMethod::Get,
+                    OperationBuilder::new().operation_id(Some("index")),
+                ),
+            )
+            .path(
+                "/",
+                PathItem::new(
+                    HttpMethod::Post,
+                    OperationBuilder::new().operation_id(Some("create")),
+                ),
+            )
+            .build();
+
+        paths.prefix("/api");
+
+        assert_eq!(paths.paths.len(), 1);
+        let item = paths.get_path_item("/api").expect("prefixed path exists");
+        assert_eq!(
+            item.get
+                .as_ref()
+                .and_then(|get| get.operation_id.as_deref()),
+            Some("index_get")
+        );
+        assert_eq!(
+            item.post
+                .as_ref()
+                .and_then(|post| post.operation_id.as_deref()),
+            Some("create_post")
+        );
+    }
+
+    #[test]
+    fn paths_get_iter_and_get_mut() {
+        let mut paths = PathsBuilder::new()
+            .path(
+                "/pets",
+                PathItem::new(
+                    HttpMethod::Get,
+                    OperationBuilder::new().operation_id(Some("get_pets")),
+                ),
+            )
+            .path(
+                "/pets/{id}",
+                PathItem::new(
+                    HttpMethod::Delete,
+                    OperationBuilder::new().operation_id(Some("delete_pet")),
+                ),
+            )
+            .build();
+
+        let paths_list = paths.iter().map(|(path, _)| path).collect::<Vec<_>>();
+        assert_eq!(paths_list, vec!["/pets", "/pets/{id}"]);
+        assert!(paths.get("/pets").is_some_and(|item| item.get.is_some()));
+        assert!(paths.get("/owners").is_none());
+
+        paths
+            .get_mut("/pets/{id}")
+            .expect("/pets/{id} path should exist")
+            .summary = Some("Delete pet".to_string());
+
+        assert_eq!(
+            paths
+                .get("/pets/{id}")
+                .and_then(|item| item.summary.as_deref()),
+            Some("Delete pet")
+        );
+        assert!(paths.get_mut("/owners").is_none());
+    }
 }
@@ -153,6 +153,19 @@ impl Response {
             ..Default::default()
         }
     }
+
+    /// Remove all [`Content`]s from the [`Response`] leaving it without response body.
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    /// Remove `example` and `examples` from all [`Content`]s of the [`Response`].
+    pub fn clear_examples(&mut self) {
+        for content in self.content.values_mut() {
+            content.example = None;
+            content.examples.clear();
+        }
+    }
 }
 
 impl ResponseBuilder {
@@ -168,6 +181,34 @@ impl ResponseBuilder {
         self
     }
 
+    /// Add multiple [`Content`]s of the [`Response`] at once from `(content_type, content)`
+    /// pairs. Contents retain the iteration order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{Content, Ref, ResponseBuilder};
+    /// let response = ResponseBuilder::new()
+    ///     .description("Pet")
+    ///     .with_contents([
+    ///         ("application/json", Content::new(Some(Ref::from_schema_name("Pet")))),
+    ///         ("application/xml", Content::new(Some(Ref::from_schema_name("Pet")))),
+    ///     ])
+    ///     .build();
+    /// ```
+    pub fn with_contents<I: IntoIterator<Item = (S, C)>, S: Into<String>, C: Into<Content>>(
+        mut self,
+        contents: I,
+    ) -> Self {
+        self.content.extend(
+            contents
+                .into_iter()
+                .map(|(content_type, content)| (content_type.into(), content.into())),
+        );
+
+        self
+    }
+
     /// Add response [`Header`].
     pub fn header<S: Into<String>>(mut self, name: S, header: Header) -> Self {
         self.headers.insert(name.into(), header);
@@ -263,6 +304,7 @@ impl ResponseExt for ResponseBuilder {
 #[cfg(test)]
 mod tests {
     use super::{Content, ResponseBuilder, Responses};
+    use crate::openapi::example::Example;
     use insta::assert_json_snapshot;
 
     #[test]
@@ -285,6 +327,104 @@ mod tests {
             .build();
         assert_json_snapshot!(request_body);
     }
+
+    #[test]
+    fn response_with_schemaless_content() {
+        let response = ResponseBuilder::new()
+            .description("Empty binary response")
+            .content(
+                "application/octet-stream",
+                Content::new(None::<crate::openapi::Ref>),
+            )
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            serde_json::json!({
+                "description": "Empty binary response",
+                "content": {
+                    "application/octet-stream": {}
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn response_with_contents() {
+        let response = ResponseBuilder::new()
+            .description("Pet")
+            .with_contents([
+                (
+                    "application/json",
+                    Content::new(Some(crate::openapi::Ref::from_schema_name("Pet"))),
+                ),
+                ("text/plain", Content::new(None::<crate::openapi::Ref>)),
+            ])
+            .build();
+
+        assert_eq!(
+            response.content.keys().collect::<Vec<_>>(),
+            ["application/json", "text/plain"]
+        );
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            serde_json::json!({
+                "description": "Pet",
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "$ref": "#/components/schemas/Pet"
+                        }
+                    },
+                    "text/plain": {}
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn response_clear_content() {
+        let mut response = ResponseBuilder::new()
+            .description("A sample response")
+            .content(
+                "application/json",
+                Content::new(Some(crate::openapi::Ref::from_schema_name(
+                    "MySchemaPayload",
+                ))),
+            )
+            .content("text/plain", Content::new(None::<crate::openapi::Ref>))
+            .build();
+
+        response.clear_content();
+
+        assert!(response.content.is_empty());
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            serde_json::json!({ "description": "A sample response" })
+        );
+    }
+
+    #[test]
+    fn response_clear_examples() {
+        let mut content = Content::new(Some(crate::openapi::Ref::from_schema_name(
+            "MySchemaPayload",
+        )));
+        content.example = Some(serde_json::json!({ "id": 1 }));
+        content
+            .examples
+            .insert("first".to_string(), Example::new().into());
+        let mut response = ResponseBuilder::new()
+            .description("A sample response")
+            .content("application/json", content)
+            .build();
+
+        response.clear_examples();
+
+        let content = response.content.get("application/json").unwrap();
+        assert!(content.example.is_none());
+        assert!(content.examples.is_empty());
+        assert!(content.schema.is_some());
+    }
 }
 
 #[cfg(all(test, feature = "openapi_extensions"))]
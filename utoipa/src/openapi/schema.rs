@@ -9,7 +9,10 @@ use serde_json::Value;
 
 use super::extensions::Extensions;
 use super::RefOr;
-use super::{builder, security::SecurityScheme, set_value, xml::Xml, Deprecated, Response};
+use super::{
+    builder, path::Parameter, request_body::RequestBody, security::SecurityScheme, set_value,
+    xml::Xml, Deprecated, Response,
+};
 use crate::{ToResponse, ToSchema};
 
 macro_rules! component_from_builder {
@@ -73,6 +76,22 @@ builder! {
         #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
         pub responses: BTreeMap<String, RefOr<Response>>,
 
+        /// Map of reusable parameter name, to [OpenAPI Parameter Object][parameter]s or [OpenAPI
+        /// Reference][reference]s to [OpenAPI Parameter Object][parameter]s.
+        ///
+        /// [parameter]: https://spec.openapis.org/oas/latest.html#parameter-object
+        /// [reference]: https://spec.openapis.org/oas/latest.html#reference-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub parameters: BTreeMap<String, RefOr<Parameter>>,
+
+        /// Map of reusable request body name, to [OpenAPI Request Body Object][request_body]s or
+        /// [OpenAPI Reference][reference]s to [OpenAPI Request Body Object][request_body]s.
+        ///
+        /// [request_body]: https://spec.openapis.org/oas/latest.html#request-body-object
+        /// [reference]: https://spec.openapis.org/oas/latest.html#reference-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub request_bodies: BTreeMap<String, RefOr<RequestBody>>,
+
         /// Map of reusable [OpenAPI Security Scheme Object][security_scheme]s.
         ///
         /// [security_scheme]: https://spec.openapis.org/oas/latest.html#security-scheme-object
@@ -127,6 +146,83 @@ impl Components {
                 .map(|(name, item)| (name.into(), item.into())),
         );
     }
+
+    /// Add [`Schema`] to [`Components`].
+    ///
+    /// Accepts two arguments where first is name of the schema and second is the schema itself.
+    /// The schema can later be referenced with [`Ref::from_schema_name`] using the same name.
+    /// Existing schema with the same name will be replaced.
+    pub fn add_schema<N: Into<String>, S: Into<RefOr<Schema>>>(&mut self, name: N, schema: S) {
+        self.schemas.insert(name.into(), schema.into());
+    }
+
+    /// Add [`Schema`] to [`Components`] consuming `self`.
+    ///
+    /// This is effectively same as calling [`Components::add_schema`] but returns `self` allowing
+    /// calls to be chained.
+    pub fn with_schema<N: Into<String>, S: Into<RefOr<Schema>>>(
+        mut self,
+        name: N,
+        schema: S,
+    ) -> Self {
+        self.add_schema(name, schema);
+
+        self
+    }
+
+    /// Merge `other` [`Components`] to `self` consuming `other`.
+    ///
+    /// All _`schemas`_, _`responses`_, _`parameters`_, _`request_bodies`_ and _`security_schemes`_
+    /// of `other` are moved to `self`.
+    /// When an item with the same name exists in both, the given [`MergePolicy`] decides which
+    /// one is retained. Only the name is used for comparison.
+    ///
+    /// # Examples
+    ///
+    /// _**Merge components overwriting existing schemas.**_
+    /// ```rust
+    /// # use utoipa::openapi::schema::{Components, ComponentsBuilder, MergePolicy, ObjectBuilder, Type};
+    /// let mut components = ComponentsBuilder::new()
+    ///     .schema("Pet", ObjectBuilder::new().schema_type(Type::String))
+    ///     .build();
+    /// let other = ComponentsBuilder::new()
+    ///     .schema("Pet", ObjectBuilder::new().schema_type(Type::Object))
+    ///     .build();
+    ///
+    /// components.merge(other, MergePolicy::Overwrite);
+    /// ```
+    pub fn merge(&mut self, mut other: Components, policy: MergePolicy) {
+        fn merge_map<T>(
+            map: &mut BTreeMap<String, T>,
+            other: &mut BTreeMap<String, T>,
+            policy: MergePolicy,
+        ) {
+            if policy == MergePolicy::KeepExisting {
+                other.retain(|name, _| !map.contains_key(name));
+            }
+            map.append(other);
+        }
+
+        merge_map(&mut self.schemas, &mut other.schemas, policy);
+        merge_map(&mut self.responses, &mut other.responses, policy);
+        merge_map(&mut self.parameters, &mut other.parameters, policy);
+        merge_map(&mut self.request_bodies, &mut other.request_bodies, policy);
+        merge_map(
+            &mut self.security_schemes,
+            &mut other.security_schemes,
+            policy,
+        );
+    }
+}
+
+/// Policy used to resolve conflicting items with the same name in [`Components::merge`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum MergePolicy {
+    /// Keep the existing item and discard the conflicting item from the other [`Components`].
+    KeepExisting,
+    /// Replace the existing item with the conflicting item from the other [`Components`].
+    Overwrite,
 }
 
 impl ComponentsBuilder {
@@ -242,6 +338,34 @@ impl ComponentsBuilder {
         self
     }
 
+    /// Add [`Parameter`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable parameter and `parameter` which is the
+    /// reusable parameter itself. The parameter can later be referenced with
+    /// [`Ref::from_parameter_name`] using the same name.
+    pub fn parameter<S: Into<String>, P: Into<RefOr<Parameter>>>(
+        mut self,
+        name: S,
+        parameter: P,
+    ) -> Self {
+        self.parameters.insert(name.into(), parameter.into());
+        self
+    }
+
+    /// Add [`RequestBody`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable request body and `request_body` which
+    /// is the reusable request body itself. The request body can later be referenced with
+    /// [`Ref::from_request_body_name`] using the same name.
+    pub fn request_body<S: Into<String>, R: Into<RefOr<RequestBody>>>(
+        mut self,
+        name: S,
+        request_body: R,
+    ) -> Self {
+        self.request_bodies.insert(name.into(), request_body.into());
+        self
+    }
+
     /// Add [`SecurityScheme`] to [`Components`].
     ///
     /// Accepts two arguments where first is the name of the [`SecurityScheme`]. This is later when
@@ -432,6 +556,18 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub discriminator: Option<Discriminator>,
 
+        /// Changes the [`OneOf`] deprecated status.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub deprecated: Option<Deprecated>,
+
+        /// Write only property will be only sent in _write_ requests like _POST, PUT_.
+        #[serde(rename = "writeOnly", skip_serializing_if = "Option::is_none")]
+        pub write_only: Option<bool>,
+
+        /// Read only property will be only sent in _read_ requests like _GET_.
+        #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+        pub read_only: Option<bool>,
+
         /// Optional extensions `x-something`.
         #[serde(skip_serializing_if = "Option::is_none", flatten)]
         pub extensions: Option<Extensions>,
@@ -477,6 +613,9 @@ impl Default for OneOf {
             example: Default::default(),
             examples: Default::default(),
             discriminator: Default::default(),
+            deprecated: Default::default(),
+            write_only: Default::default(),
+            read_only: Default::default(),
             extensions: Default::default(),
         }
     }
@@ -531,6 +670,21 @@ impl OneOfBuilder {
         set_value!(self discriminator discriminator)
     }
 
+    /// Add or change deprecated status for [`OneOf`].
+    pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
+        set_value!(self deprecated deprecated)
+    }
+
+    /// Add or change write only flag for [`OneOf`].
+    pub fn write_only(mut self, write_only: Option<bool>) -> Self {
+        set_value!(self write_only write_only)
+    }
+
+    /// Add or change read only flag for [`OneOf`].
+    pub fn read_only(mut self, read_only: Option<bool>) -> Self {
+        set_value!(self read_only read_only)
+    }
+
     /// Add openapi extensions (`x-something`) for [`OneOf`].
     pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
         set_value!(self extensions extensions)
@@ -1422,6 +1576,21 @@ impl Ref {
         Self::new(format!("#/components/responses/{}", response_name.into()))
     }
 
+    /// Construct a new [`Ref`] from provided parameter name. This will create a [`Ref`] that
+    /// references the reusable parameter.
+    pub fn from_parameter_name<I: Into<String>>(parameter_name: I) -> Self {
+        Self::new(format!("#/components/parameters/{}", parameter_name.into()))
+    }
+
+    /// Construct a new [`Ref`] from provided request body name. This will create a [`Ref`] that
+    /// references the reusable request body.
+    pub fn from_request_body_name<I: Into<String>>(request_body_name: I) -> Self {
+        Self::new(format!(
+            "#/components/requestBodies/{}",
+            request_body_name.into()
+        ))
+    }
+
     to_array_builder!();
 }
 
@@ -1802,6 +1971,46 @@ impl ArrayBuilder {
         set_value!(self examples examples.into_iter().map(Into::into).collect())
     }
 
+    /// Add or change example of the [`Array`] items shown in UI for richer documentation.
+    ///
+    /// The example is set to the schema already defined with [`ArrayBuilder::items`] thus this
+    /// must be called after the items are defined. Referenced items are wrapped within
+    /// [`AllOf`] since [`Ref`] itself cannot hold examples.
+    ///
+    /// # Examples
+    ///
+    /// _**Set example for the items of `String` array.**_
+    /// ```rust
+    /// # use utoipa::openapi::schema::{ArrayBuilder, Object, Type};
+    /// # use serde_json::json;
+    /// let _ = ArrayBuilder::new()
+    ///     .items(Object::with_type(Type::String))
+    ///     .item_example(Some(json!("item")))
+    ///     .build();
+    /// ```
+    pub fn item_example(mut self, example: Option<Value>) -> Self {
+        let (Some(example), ArrayItems::RefOrSchema(items)) = (example, &mut self.items) else {
+            return self;
+        };
+
+        match items.as_mut() {
+            RefOr::T(Schema::Object(object)) => object.examples = vec![example],
+            RefOr::T(Schema::Array(array)) => array.examples = vec![example],
+            RefOr::T(Schema::OneOf(one_of)) => one_of.examples = vec![example],
+            RefOr::T(Schema::AllOf(all_of)) => all_of.examples = vec![example],
+            RefOr::T(Schema::AnyOf(any_of)) => any_of.examples = vec![example],
+            RefOr::Ref(reference) => {
+                let all_of = AllOfBuilder::new()
+                    .item(reference.clone())
+                    .examples([example])
+                    .build();
+                **items = RefOr::T(Schema::AllOf(all_of));
+            }
+        }
+
+        self
+    }
+
     /// Add or change default value for the object which is provided when user has not provided the input in Swagger UI.
     pub fn default(mut self, default: Option<Value>) -> Self {
         set_value!(self default default)
@@ -2031,18 +2240,22 @@ pub enum KnownFormat {
     /// 8 bit unsigned integer.
     #[cfg(feature = "non_strict_integers")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "non_strict_integers")))]
+    #[serde(rename = "uint8")]
     UInt8,
     /// 16 bit unsigned integer.
     #[cfg(feature = "non_strict_integers")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "non_strict_integers")))]
+    #[serde(rename = "uint16")]
     UInt16,
     /// 32 bit unsigned integer.
     #[cfg(feature = "non_strict_integers")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "non_strict_integers")))]
+    #[serde(rename = "uint32")]
     UInt32,
     /// 64 bit unsigned integer.
     #[cfg(feature = "non_strict_integers")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "non_strict_integers")))]
+    #[serde(rename = "uint64")]
     UInt64,
     /// floating point number.
     Float,
@@ -2131,6 +2344,143 @@ mod tests {
     use super::*;
     use crate::openapi::*;
 
+    #[test]
+    fn ref_from_component_names() {
+        assert_eq!(
+            Ref::from_schema_name("Pet").ref_location,
+            "#/components/schemas/Pet"
+        );
+        assert_eq!(
+            Ref::from_response_name("NotFound").ref_location,
+            "#/components/responses/NotFound"
+        );
+        assert_eq!(
+            Ref::from_parameter_name("PetId").ref_location,
+            "#/components/parameters/PetId"
+        );
+        assert_eq!(
+            Ref::from_request_body_name("NewPet").ref_location,
+            "#/components/requestBodies/NewPet"
+        );
+    }
+
+    #[test]
+    fn components_with_parameters_and_request_bodies() {
+        use crate::openapi::{path::ParameterBuilder, request_body::RequestBodyBuilder};
+
+        let components = ComponentsBuilder::new()
+            .parameter(
+                "PetId",
+                ParameterBuilder::new()
+                    .name("id")
+                    .schema(Some(Object::with_type(Type::Integer))),
+            )
+            .request_body(
+                "NewPet",
+                RequestBodyBuilder::new().content(
+                    "application/json",
+                    Content::new(Some(Ref::from_schema_name("Pet"))),
+                ),
+            )
+            .build();
+
+        let value = serde_json::to_value(&components).unwrap();
+        assert_eq!(value.pointer("/parameters/PetId/name"), Some(&json!("id")));
+        assert_eq!(
+            value.pointer("/requestBodies/NewPet/content/application~1json/schema/$ref"),
+            Some(&json!("#/components/schemas/Pet"))
+        );
+    }
+
+    #[test]
+    fn known_format_serializes_to_openapi_spelling() {
+        let formats = [
+            (KnownFormat::Email, "email"),
+            (KnownFormat::Hostname, "hostname"),
+            (KnownFormat::Ipv4, "ipv4"),
+            (KnownFormat::Ipv6, "ipv6"),
+            #[cfg(feature = "uuid")]
+            (KnownFormat::Uuid, "uuid"),
+            #[cfg(feature = "url")]
+            (KnownFormat::Uri, "uri"),
+        ];
+
+        for (format, expected) in formats {
+            assert_eq!(
+                serde_json::to_value(SchemaFormat::KnownFormat(format)).unwrap(),
+                json!(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn components_add_schema_referenced_in_response() {
+        let mut components = Components::new().with_schema("Pet", Object::with_type(Type::Object));
+        components.add_schema("Name", Object::with_type(Type::String));
+
+        let response = ResponseBuilder::new()
+            .description("Pet found")
+            .content(
+                "application/json",
+                Content::new(Some(Ref::from_schema_name("Pet"))),
+            )
+            .build();
+
+        assert_eq!(
+            components.schemas.keys().collect::<Vec<_>>(),
+            vec!["Name", "Pet"]
+        );
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            json!({
+                "description": "Pet found",
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "$ref": "#/components/schemas/Pet"
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn components_merge_with_policy() {
+        fn create_components() -> (Components, Components) {
+            let components = ComponentsBuilder::new()
+                .schema("Pet", Object::with_type(Type::String))
+                .response("NotFound", Response::new("Not found"))
+                .build();
+            let other = ComponentsBuilder::new()
+                .schema("Pet", Object::with_type(Type::Integer))
+                .schema("Owner", Object::with_type(Type::Object))
+                .build();
+
+            (components, other)
+        }
+
+        let (mut components, other) = create_components();
+        components.merge(other, MergePolicy::KeepExisting);
+
+        assert_eq!(
+            components.schemas.get("Pet"),
+            Some(&RefOr::T(Schema::Object(Object::with_type(Type::String))))
+        );
+        assert!(components.schemas.contains_key("Owner"));
+        assert!(components.responses.contains_key("NotFound"));
+
+        let (mut components, other) = create_components();
+        components.merge(other, MergePolicy::Overwrite);
+
+        assert_eq!(
+            components.schemas.get("Pet"),
+            Some(&RefOr::T(Schema::Object(Object::with_type(Type::Integer))))
+        );
+        assert!(components.schemas.contains_key("Owner"));
+        assert!(components.responses.contains_key("NotFound"));
+    }
+
     #[test]
     fn create_schema_serializes_json() -> Result<(), serde_json::Error> {
         let openapi = OpenApiBuilder::new()
@@ -2347,6 +2697,31 @@ mod tests {
         "#);
     }
 
+    #[test]
+    fn object_with_read_only_and_write_only() {
+        let read_only = ObjectBuilder::new()
+            .schema_type(Type::Integer)
+            .read_only(Some(true))
+            .build();
+        assert_json_snapshot!(read_only, @r#"
+        {
+          "type": "integer",
+          "readOnly": true
+        }
+        "#);
+
+        let write_only = ObjectBuilder::new()
+            .schema_type(Type::String)
+            .write_only(Some(true))
+            .build();
+        assert_json_snapshot!(write_only, @r#"
+        {
+          "type": "string",
+          "writeOnly": true
+        }
+        "#);
+    }
+
     #[test]
     fn derive_object_with_examples() {
         let json_value = ObjectBuilder::new()
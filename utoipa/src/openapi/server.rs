@@ -111,6 +111,54 @@ impl Server {
             ..Default::default()
         }
     }
+
+    /// Resolve concrete url of the [`Server`] by substituting `{variable}` placeholders in
+    /// [`Server::url`].
+    ///
+    /// Value from `overrides` is used when present, otherwise the
+    /// [`ServerVariable::default_value`] of the matching variable is used. Placeholders without
+    /// override or variable are left as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::BTreeMap;
+    /// # use utoipa::openapi::server::{ServerBuilder, ServerVariableBuilder};
+    /// let server = ServerBuilder::new()
+    ///     .url("/api/{version}")
+    ///     .parameter("version", ServerVariableBuilder::new().default_value("v1"))
+    ///     .build();
+    ///
+    /// assert_eq!(server.resolved_url(&BTreeMap::new()), "/api/v1");
+    /// ```
+    pub fn resolved_url(&self, overrides: &BTreeMap<String, String>) -> String {
+        let mut resolved = String::with_capacity(self.url.len());
+        let mut rest = self.url.as_str();
+
+        while let Some(start) = rest.find('{') {
+            let Some(end) = rest[start..].find('}').map(|end| start + end) else {
+                break;
+            };
+            let name = &rest[start + 1..end];
+            resolved.push_str(&rest[..start]);
+
+            let value = overrides.get(name).map(String::as_str).or_else(|| {
+                self.variables
+                    .as_ref()
+                    .and_then(|variables| variables.get(name))
+                    .map(|variable| variable.default_value.as_str())
+            });
+            match value {
+                Some(value) => resolved.push_str(value),
+                None => resolved.push_str(&rest[start..=end]),
+            }
+
+            rest = &rest[end + 1..];
+        }
+        resolved.push_str(rest);
+
+        resolved
+    }
 }
 
 impl ServerBuilder {
@@ -259,4 +307,23 @@ mod tests {
   }
 }"###
     }
+
+    #[test]
+    fn server_resolved_url_with_overridden_and_default_variables() {
+        let server = ServerBuilder::new()
+            .url("https://{username}.pet-api.test/api/{version}")
+            .parameter(
+                "username",
+                ServerVariableBuilder::new().default_value("the_user"),
+            )
+            .parameter("version", ServerVariableBuilder::new().default_value("v1"))
+            .build();
+
+        let overrides = BTreeMap::from_iter([("version".to_string(), "v2".to_string())]);
+
+        assert_eq!(
+            server.resolved_url(&overrides),
+            "https://the_user.pet-api.test/api/v2"
+        );
+    }
 }
@@ -0,0 +1,306 @@
+//! Implements aggregate validation of the [`OpenApi`] document. See [`OpenApi::validate`] for
+//! more details.
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+use serde_json::Value;
+
+use super::path::{Operation, Parameter, ParameterIn};
+use super::schema::SchemaType;
+use super::{walk, OpenApi, PathItem, Ref, RefOr, Schema, Type};
+
+/// Single problem found by [`OpenApi::validate`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// Local `$ref` pointing to a location that does not exist in the document.
+    DanglingRef {
+        /// The unresolved reference e.g. `#/components/schemas/Pet`.
+        reference: String,
+    },
+    /// Same _`operationId`_ is used by more than one [`Operation`].
+    DuplicateOperationId {
+        /// The duplicated operation id.
+        operation_id: String,
+    },
+    /// [`ParameterIn::Path`] parameter that does not match any `{placeholder}` in the path.
+    UnknownPathParameter {
+        /// Path of the [`PathItem`] e.g. `/pets/{id}`.
+        path: String,
+        /// Name of the parameter.
+        name: String,
+    },
+    /// `{placeholder}` in the path without matching [`ParameterIn::Path`] parameter.
+    MissingPathParameter {
+        /// Path of the [`PathItem`] e.g. `/pets/{id}`.
+        path: String,
+        /// Name of the placeholder.
+        name: String,
+    },
+    /// [`Parameter`] example that does not match the type of the parameter's inline schema.
+    ExampleTypeMismatch {
+        /// Path of the [`PathItem`] e.g. `/pets/{id}`.
+        path: String,
+        /// Name of the parameter.
+        name: String,
+    },
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DanglingRef { reference } => {
+                write!(
+                    f,
+                    "reference `{reference}` does not resolve within the document"
+                )
+            }
+            Self::DuplicateOperationId { operation_id } => {
+                write!(
+                    f,
+                    "operation id `{operation_id}` is used by multiple operations"
+                )
+            }
+            Self::UnknownPathParameter { path, name } => write!(
+                f,
+                "path parameter `{name}` does not match any placeholder in path `{path}`"
+            ),
+            Self::MissingPathParameter { path, name } => write!(
+                f,
+                "path placeholder `{{{name}}}` in path `{path}` has no matching path parameter"
+            ),
+            Self::ExampleTypeMismatch { path, name } => write!(
+                f,
+                "example of parameter `{name}` in path `{path}` does not match the parameter type"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+pub(super) fn validate(openapi: &OpenApi) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+
+    dangling_refs(openapi, &mut errors);
+    duplicate_operation_ids(openapi, &mut errors);
+    for (path, path_item) in openapi.paths.paths.iter() {
+        path_parameters(openapi, path, path_item, &mut errors);
+        parameter_examples(openapi, path, path_item, &mut errors);
+    }
+
+    errors
+}
+
+fn operations(path_item: &PathItem) -> impl Iterator<Item = &Operation> {
+    [
+        &path_item.get,
+        &path_item.put,
+        &path_item.post,
+        &path_item.delete,
+        &path_item.options,
+        &path_item.head,
+        &path_item.patch,
+        &path_item.trace,
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// Get [`Operation`] parameters resolving parameters referenced from
+/// [`Components`][super::Components]. Unresolved references are skipped as they are reported as
+/// dangling references.
+fn operation_parameters<'o>(
+    openapi: &'o OpenApi,
+    operation: &'o Operation,
+) -> impl Iterator<Item = &'o Parameter> {
+    operation
+        .parameters
+        .iter()
+        .flatten()
+        .filter_map(|parameter| match parameter {
+            RefOr::T(parameter) => Some(parameter),
+            RefOr::Ref(reference) => reference
+                .ref_location
+                .strip_prefix("#/components/parameters/")
+                .and_then(|name| openapi.components.as_ref()?.parameters.get(name))
+                .and_then(|parameter| match parameter {
+                    RefOr::T(parameter) => Some(parameter),
+                    RefOr::Ref(_) => None,
+                }),
+        })
+}
+
+fn dangling_refs(openapi: &OpenApi, errors: &mut Vec<ValidationError>) {
+    let mut references = BTreeSet::new();
+    walk::shared::openapi(
+        openapi,
+        &mut walk::shared::RefVisitor(|reference: &Ref| {
+            references.insert(reference.ref_location.clone());
+        }),
+    );
+
+    // most refs point to components which are looked up directly, the document is serialized
+    // only for the JSON pointers pointing elsewhere
+    let mut document = None;
+    errors.extend(references.into_iter().filter_map(|reference| {
+        let pointer = reference.strip_prefix('#')?;
+        let exists = match component_exists(openapi, pointer) {
+            Some(exists) => exists,
+            None => document
+                .get_or_insert_with(|| serde_json::to_value(openapi).ok())
+                .as_ref()
+                .map_or(true, |document: &Value| document.pointer(pointer).is_some()),
+        };
+
+        (!exists).then_some(ValidationError::DanglingRef { reference })
+    }));
+}
+
+/// Check whether JSON pointer e.g. `/components/schemas/Pet` points to an existing component.
+/// Returns `None` if the pointer does not point directly to a component.
+fn component_exists(openapi: &OpenApi, pointer: &str) -> Option<bool> {
+    let (kind, name) = pointer.strip_prefix("/components/")?.split_once('/')?;
+    if name.contains('/') {
+        return None;
+    }
+    let name = name.replace("~1", "/").replace("~0", "~");
+    let components = openapi.components.as_ref();
+    let exists = match kind {
+        "schemas" => components.is_some_and(|c| c.schemas.contains_key(&name)),
+        "responses" => components.is_some_and(|c| c.responses.contains_key(&name)),
+        "parameters" => components.is_some_and(|c| c.parameters.contains_key(&name)),
+        "requestBodies" => components.is_some_and(|c| c.request_bodies.contains_key(&name)),
+        _ => return None,
+    };
+
+    Some(exists)
+}
+
+fn duplicate_operation_ids(openapi: &OpenApi, errors: &mut Vec<ValidationError>) {
+    let mut seen = BTreeSet::new();
+    let mut duplicates = BTreeSet::new();
+
+    openapi
+        .paths
+        .paths
+        .values()
+        .flat_map(operations)
+        .filter_map(|operation| operation.operation_id.as_deref())
+        .for_each(|operation_id| {
+            if !seen.insert(operation_id) {
+                duplicates.insert(operation_id);
+            }
+        });
+
+    errors.extend(duplicates.into_iter().map(|operation_id| {
+        ValidationError::DuplicateOperationId {
+            operation_id: operation_id.to_string(),
+        }
+    }));
+}
+
+/// Get names of `{placeholder}`s in path template. Possible regex e.g. `{id:\d+}` and wildcard
+/// e.g. `{*rest}` markers are stripped from the name.
+fn path_placeholders(path: &str) -> Vec<&str> {
+    path.split('{')
+        .skip(1)
+        .filter_map(|segment| segment.split_once('}'))
+        .map(|(placeholder, _)| {
+            let placeholder = placeholder
+                .split_once(':')
+                .map_or(placeholder, |(name, _)| name);
+            placeholder.trim_start_matches('*').trim()
+        })
+        .collect()
+}
+
+fn path_parameters(
+    openapi: &OpenApi,
+    path: &str,
+    path_item: &PathItem,
+    errors: &mut Vec<ValidationError>,
+) {
+    let placeholders = path_placeholders(path);
+    let common_parameters = path_item.parameters.iter().flatten();
+
+    let mut push = |error: ValidationError| {
+        if !errors.contains(&error) {
+            errors.push(error);
+        }
+    };
+
+    for operation in operations(path_item) {
+        let path_parameter_names = common_parameters
+            .clone()
+            .chain(operation_parameters(openapi, operation))
+            .filter(|parameter| parameter.parameter_in == ParameterIn::Path)
+            .map(|parameter| parameter.name.as_str())
+            .collect::<BTreeSet<_>>();
+
+        path_parameter_names
+            .iter()
+            .filter(|name| !placeholders.contains(name))
+            .for_each(|name| {
+                push(ValidationError::UnknownPathParameter {
+                    path: path.to_string(),
+                    name: name.to_string(),
+                })
+            });
+        placeholders
+            .iter()
+            .filter(|name| !path_parameter_names.contains(*name))
+            .for_each(|name| {
+                push(ValidationError::MissingPathParameter {
+                    path: path.to_string(),
+                    name: name.to_string(),
+                })
+            });
+    }
+}
+
+fn parameter_examples(
+    openapi: &OpenApi,
+    path: &str,
+    path_item: &PathItem,
+    errors: &mut Vec<ValidationError>,
+) {
+    fn is_type(value: &Value, schema_type: &Type) -> bool {
+        match schema_type {
+            Type::Object => value.is_object(),
+            Type::String => value.is_string(),
+            Type::Integer => value.is_i64() || value.is_u64(),
+            Type::Number => value.is_number(),
+            Type::Boolean => value.is_boolean(),
+            Type::Array => value.is_array(),
+            Type::Null => value.is_null(),
+        }
+    }
+
+    fn example_matches(parameter: &Parameter) -> bool {
+        match (&parameter.example, &parameter.schema) {
+            (Some(example), Some(RefOr::T(Schema::Object(object)))) => match &object.schema_type {
+                SchemaType::Type(schema_type) => is_type(example, schema_type),
+                SchemaType::Array(schema_types) => schema_types
+                    .iter()
+                    .any(|schema_type| is_type(example, schema_type)),
+                SchemaType::AnyValue => true,
+            },
+            _ => true,
+        }
+    }
+
+    let parameters = path_item.parameters.iter().flatten().chain(
+        operations(path_item).flat_map(|operation| operation_parameters(openapi, operation)),
+    );
+
+    for parameter in parameters {
+        let error = ValidationError::ExampleTypeMismatch {
+            path: path.to_string(),
+            name: parameter.name.clone(),
+        };
+        if !example_matches(parameter) && !errors.contains(&error) {
+            errors.push(error);
+        }
+    }
+}